@@ -1,4 +1,4 @@
-use super::BezierCurve;
+use super::{subdivide, BezierCurve};
 use cgmath::num_traits::Float;
 use cgmath::MetricSpace;
 use std::ops;
@@ -23,3 +23,337 @@ where
     }
     (lower, upper)
 }
+
+/// Maximum subdivision depth for [`arclen`], which keeps unreachable tolerances from recursing
+/// forever and bounds a single call to 2¹⁶ pieces.
+const ARCLEN_MAX_DEPTH: usize = 16;
+
+/// Returns the arc length of a bézier curve, accurate to within the given tolerance.
+///
+/// Subdivision stops at a fixed depth, so tolerances far below the length of the curve divided by
+/// 2¹⁶ may not be reached.
+///
+/// # Details
+/// The curve is subdivided adaptively until the gap between the bounds from
+/// [`hull_arclen_bounds`] is within tolerance for every piece. The length of each piece is then
+/// estimated using the weighted average from "Adaptive subdivision and the length and energy of
+/// Bézier curves" by Jens Gravesen (1997), which lies within the bounds.
+pub fn arclen<S, P, V, L>(points: &L, tolerance: S) -> S
+where
+    L: BezierCurve<P>,
+    P: MetricSpace<Metric = S> + ops::Sub<P, Output = V> + ops::Add<V, Output = P> + Clone,
+    V: ops::Mul<S, Output = V>,
+    S: Float + ops::AddAssign<S>,
+{
+    fn arclen_impl<S, P, V, L>(points: &L, tolerance: S, depth: usize) -> S
+    where
+        L: BezierCurve<P>,
+        P: MetricSpace<Metric = S> + ops::Sub<P, Output = V> + ops::Add<V, Output = P> + Clone,
+        V: ops::Mul<S, Output = V>,
+        S: Float + ops::AddAssign<S>,
+    {
+        let (lower, upper) = hull_arclen_bounds(points);
+        if upper - lower <= tolerance || depth >= ARCLEN_MAX_DEPTH {
+            let one = S::one();
+            let two = one + one;
+            let n = S::from(points.count() - 1).expect("could not cast degree to scalar type");
+            (two * lower + (n - one) * upper) / (n + one)
+        } else {
+            let half = S::from(0.5).unwrap();
+            let (a, b) = subdivide(points, half);
+            arclen_impl(&a, tolerance * half, depth + 1)
+                + arclen_impl(&b, tolerance * half, depth + 1)
+        }
+    }
+
+    arclen_impl(points, tolerance, 0)
+}
+
+/// Returns the arc length of a bézier curve between parameters t0 and t1, accurate to within the
+/// given tolerance.
+///
+/// Both parameters are clamped to [0, 1], and their order does not matter: the result is the
+/// (never negative) length between them.
+pub fn arclen_range<S, P, V, L>(points: &L, t0: S, t1: S, tolerance: S) -> S
+where
+    L: BezierCurve<P>,
+    P: MetricSpace<Metric = S> + ops::Sub<P, Output = V> + ops::Add<V, Output = P> + Clone,
+    V: ops::Mul<S, Output = V>,
+    S: Float + ops::AddAssign<S>,
+{
+    let clamp = |t: S| t.max(S::zero()).min(S::one());
+    let (t0, t1) = (clamp(t0), clamp(t1));
+    let (t0, t1) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+    if t1 <= S::zero() || t0 == t1 {
+        return S::zero();
+    }
+    let (head, _) = subdivide(points, t1);
+    let (_, range) = subdivide(&head, t0 / t1);
+    arclen(&range, tolerance)
+}
+
+/// Returns the parameter t at which the arc length from the start of the curve is s, accurate to
+/// within the given arc length tolerance.
+///
+/// s is clamped to the length of the curve. To perform many queries on the same curve, use an
+/// [`ArclenTable`] instead.
+pub fn param_at_arclen<S, P, V, L>(points: &L, s: S, tolerance: S) -> S
+where
+    L: BezierCurve<P>,
+    P: MetricSpace<Metric = S> + ops::Sub<P, Output = V> + ops::Add<V, Output = P> + Clone,
+    V: ops::Mul<S, Output = V>,
+    S: Float + ops::AddAssign<S>,
+{
+    ArclenTable::new(points, 1, tolerance).param_at(s)
+}
+
+/// Precomputed arc lengths of a bézier curve, for repeatedly converting between arc length and
+/// curve parameter (e.g. for constant-speed motion along a curve).
+///
+/// # Examples
+/// ```
+/// # use cgmath::Point2;
+/// # use cgmath::assert_abs_diff_eq;
+/// # use algeo::bezier::ArclenTable;
+/// let curve = [
+///     Point2::new(0., 2.),
+///     Point2::new(4., 3.),
+///     Point2::new(6., 0.),
+///     Point2::new(9., 4.),
+/// ];
+/// let table = ArclenTable::new(&curve, 8, 1e-6);
+/// let t = table.param_at(table.total() / 2.);
+/// assert_abs_diff_eq!(table.arclen_at(t), table.total() / 2., epsilon = 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArclenTable<S, L> {
+    curve: L,
+    /// Tolerance for each segment.
+    tolerance: S,
+    /// Arc length at the start of each segment, and the total length at the end.
+    lengths: Vec<S>,
+}
+
+impl<S, L> ArclenTable<S, L>
+where
+    S: Float + ops::AddAssign<S>,
+{
+    /// Creates a new arc length table by splitting the curve into the given number of segments of
+    /// equal parameter range.
+    ///
+    /// Arc lengths are accurate to within the given tolerance. More segments make the table more
+    /// expensive to create, but queries cheaper.
+    ///
+    /// # Panics
+    /// - if the number of segments is zero
+    pub fn new<P, V>(points: &L, segments: usize, tolerance: S) -> Self
+    where
+        L: BezierCurve<P>,
+        P: MetricSpace<Metric = S> + ops::Sub<P, Output = V> + ops::Add<V, Output = P> + Clone,
+        V: ops::Mul<S, Output = V>,
+    {
+        assert!(segments > 0, "arc length table needs at least one segment");
+        let n = S::from(segments).expect("could not cast segment count to scalar type");
+        let tolerance = tolerance / n;
+
+        let mut lengths = Vec::with_capacity(segments + 1);
+        let mut length = S::zero();
+        lengths.push(length);
+        for i in 0..segments {
+            let t0 = S::from(i).unwrap() / n;
+            let t1 = S::from(i + 1).unwrap() / n;
+            length += arclen_range(points, t0, t1, tolerance);
+            lengths.push(length);
+        }
+
+        ArclenTable {
+            curve: points.clone(),
+            tolerance,
+            lengths,
+        }
+    }
+
+    fn segments(&self) -> usize {
+        self.lengths.len() - 1
+    }
+
+    fn segment_start(&self, i: usize) -> S {
+        S::from(i).unwrap() / S::from(self.segments()).unwrap()
+    }
+
+    /// Returns the total arc length of the curve.
+    pub fn total(&self) -> S {
+        self.lengths[self.segments()]
+    }
+
+    /// Returns the arc length from the start of the curve to the parameter t.
+    ///
+    /// t is clamped to [0, 1].
+    pub fn arclen_at<P, V>(&self, t: S) -> S
+    where
+        L: BezierCurve<P>,
+        P: MetricSpace<Metric = S> + ops::Sub<P, Output = V> + ops::Add<V, Output = P> + Clone,
+        V: ops::Mul<S, Output = V>,
+    {
+        let t = t.max(S::zero()).min(S::one());
+        let n = S::from(self.segments()).unwrap();
+        let i = (t * n)
+            .floor()
+            .to_usize()
+            .unwrap_or(0)
+            .min(self.segments() - 1);
+        self.lengths[i] + arclen_range(&self.curve, self.segment_start(i), t, self.tolerance)
+    }
+
+    /// Returns the parameter t at which the arc length from the start of the curve is s.
+    ///
+    /// s is clamped to the length of the curve.
+    pub fn param_at<P, V>(&self, s: S) -> S
+    where
+        L: BezierCurve<P>,
+        P: MetricSpace<Metric = S> + ops::Sub<P, Output = V> + ops::Add<V, Output = P> + Clone,
+        V: ops::Mul<S, Output = V>,
+    {
+        if s <= S::zero() {
+            return S::zero();
+        }
+        if s >= self.total() {
+            return S::one();
+        }
+
+        let i = (self.lengths.partition_point(|l| *l <= s) - 1).min(self.segments() - 1);
+        let start = self.segment_start(i);
+        let f = |t: S| self.lengths[i] + arclen_range(&self.curve, start, t, self.tolerance) - s;
+
+        // arc length is monotonic in t, so solve using the Illinois variant of regula falsi
+        let (mut lo, mut hi) = (start, self.segment_start(i + 1));
+        let (mut f_lo, mut f_hi) = (self.lengths[i] - s, self.lengths[i + 1] - s);
+        let mut side = 0;
+        let mut t = lo;
+        for _ in 0..64 {
+            t = if f_hi > f_lo {
+                hi - f_hi * (hi - lo) / (f_hi - f_lo)
+            } else {
+                (lo + hi) / (S::one() + S::one())
+            };
+            let f_t = f(t);
+            if f_t.abs() <= self.tolerance || hi - lo <= S::epsilon() {
+                break;
+            }
+            if f_t < S::zero() {
+                lo = t;
+                f_lo = f_t;
+                if side == -1 {
+                    f_hi = f_hi / (S::one() + S::one());
+                }
+                side = -1;
+            } else {
+                hi = t;
+                f_hi = f_t;
+                if side == 1 {
+                    f_lo = f_lo / (S::one() + S::one());
+                }
+                side = 1;
+            }
+        }
+        t
+    }
+}
+
+#[test]
+fn test_arclen() {
+    use super::evaluate;
+    use cgmath::assert_abs_diff_eq;
+    use cgmath::{Point2, Point3};
+
+    fn polyline_len<P, F>(f: F) -> f64
+    where
+        P: MetricSpace<Metric = f64>,
+        F: Fn(f64) -> P,
+    {
+        let n = 10_000;
+        (0..n)
+            .map(|i| f(i as f64 / n as f64).distance(f((i + 1) as f64 / n as f64)))
+            .sum()
+    }
+
+    // a straight line with uneven parametrization
+    let line = [
+        Point2::new(0., 0.),
+        Point2::new(1., 0.),
+        Point2::new(1.5, 0.),
+        Point2::new(3., 0.),
+    ];
+    assert_abs_diff_eq!(arclen(&line, 1e-6), 3., epsilon = 1e-6);
+    assert_abs_diff_eq!(arclen_range(&line, 0., 1., 1e-6), 3., epsilon = 1e-6);
+
+    let curve = [
+        Point2::new(0., 2.),
+        Point2::new(4., 3.),
+        Point2::new(6., 0.),
+        Point2::new(9., 4.),
+    ];
+    let reference = polyline_len(|t| evaluate(&curve, t));
+    assert_abs_diff_eq!(arclen(&curve, 1e-6), reference, epsilon = 1e-6);
+
+    let reference = polyline_len(|t| evaluate(&curve, 0.2 + 0.5 * t));
+    assert_abs_diff_eq!(
+        arclen_range(&curve, 0.2, 0.7, 1e-6),
+        reference,
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        arclen_range(&curve, 0.7, 0.2, 1e-6),
+        reference,
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(arclen_range(&curve, 0.4, 0.4, 1e-6), 0.);
+    let total = arclen(&curve, 1e-6);
+    assert_abs_diff_eq!(arclen_range(&curve, -1., 2., 1e-6), total, epsilon = 1e-6);
+    assert_abs_diff_eq!(arclen_range(&curve, 1.5, 0., 1e-6), total, epsilon = 1e-6);
+    assert_abs_diff_eq!(arclen_range(&curve, 1., 3., 1e-6), 0.);
+
+    let curve = [
+        Point3::new(0., 2., 1.),
+        Point3::new(4., 3., -1.),
+        Point3::new(6., 0., 5.),
+        Point3::new(9., 4., 2.),
+        Point3::new(3., 1., 0.),
+    ];
+    let reference = polyline_len(|t| evaluate(&curve, t));
+    assert_abs_diff_eq!(arclen(&curve, 1e-6), reference, epsilon = 1e-6);
+}
+
+#[test]
+fn test_arclen_table() {
+    use cgmath::assert_abs_diff_eq;
+    use cgmath::Point2;
+
+    let curve = [
+        Point2::new(0., 2.),
+        Point2::new(4., 3.),
+        Point2::new(6., 0.),
+        Point2::new(9., 4.),
+    ];
+    let total = arclen(&curve, 1e-6);
+
+    for segments in [1, 5, 16].iter() {
+        let table = ArclenTable::new(&curve, *segments, 1e-6);
+        assert_abs_diff_eq!(table.total(), total, epsilon = 1e-5);
+        assert_abs_diff_eq!(table.param_at(-1.), 0.);
+        assert_abs_diff_eq!(table.param_at(total + 1.), 1.);
+        assert_abs_diff_eq!(table.arclen_at(1.), total, epsilon = 1e-5);
+
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+            let s = table.arclen_at(t);
+            assert_abs_diff_eq!(s, arclen_range(&curve, 0., t, 1e-6), epsilon = 1e-5);
+            assert_abs_diff_eq!(table.param_at(s), t, epsilon = 1e-5);
+
+            let s = total * t;
+            let t = table.param_at(s);
+            assert_abs_diff_eq!(table.arclen_at(t), s, epsilon = 1e-5);
+            assert_abs_diff_eq!(param_at_arclen(&curve, s, 1e-6), t, epsilon = 1e-5);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use super::{evaluate, parametric, BezierCurve};
+use cgmath::{BaseFloat, EuclideanSpace};
+
+/// A tight axis-aligned bounding box of a bézier curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundingBox<S, P> {
+    /// Corner with the smallest coordinates.
+    pub min: P,
+    /// Corner with the largest coordinates.
+    pub max: P,
+    /// For each axis, the curve parameter at which the minimum coordinate is attained.
+    pub min_t: Vec<S>,
+    /// For each axis, the curve parameter at which the maximum coordinate is attained.
+    pub max_t: Vec<S>,
+}
+
+/// Returns the tight axis-aligned bounding box of a bézier curve.
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+///
+/// # Details
+/// Extrema along each axis are either at the endpoints or at roots of the derivative of the
+/// corresponding component of the parametric form (see [`parametric`]).
+pub fn bounding_box<S, P, L>(points: &L) -> BoundingBox<S, P>
+where
+    L: BezierCurve<P>,
+    P: EuclideanSpace<Scalar = S>,
+    S: BaseFloat,
+{
+    let power = parametric(points);
+    let mut min = *points.get(0);
+    let mut max = min;
+    let mut min_t = vec![S::zero(); P::len()];
+    let mut max_t = vec![S::zero(); P::len()];
+
+    for axis in 0..P::len() {
+        let derivative = power[axis].derivative();
+        let mut candidates = derivative.real_roots_in(S::zero(), S::one());
+        candidates.push(S::one());
+
+        for t in candidates {
+            let value = evaluate(points, t)[axis];
+            if value < min[axis] {
+                min[axis] = value;
+                min_t[axis] = t;
+            }
+            if value > max[axis] {
+                max[axis] = value;
+                max_t[axis] = t;
+            }
+        }
+    }
+
+    BoundingBox {
+        min,
+        max,
+        min_t,
+        max_t,
+    }
+}
+
+#[test]
+fn test_bounding_box() {
+    use cgmath::assert_abs_diff_eq;
+    use cgmath::{Point2, Point3};
+
+    // (1 - t)² (0, 0) + 2t(1 - t) (1, 2) + t² (2, 0) = (2t, 4t - 4t²), with a maximum at t = 0.5
+    let quadratic = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(2., 0.),
+    ];
+    let bbox = bounding_box(&quadratic);
+    assert_abs_diff_eq!(bbox.min, Point2::new(0., 0.));
+    assert_abs_diff_eq!(bbox.max, Point2::new(2., 1.));
+    assert_eq!(bbox.min_t, vec![0., 0.]);
+    assert_abs_diff_eq!(bbox.max_t[0], 1.);
+    assert_abs_diff_eq!(bbox.max_t[1], 0.5);
+
+    let curve = [
+        Point3::new(0., 2., 1.),
+        Point3::new(4., 3., -1.),
+        Point3::new(6., 0., 5.),
+        Point3::new(-3., 4., 2.),
+        Point3::new(3., 1., 0.),
+    ];
+    let bbox = bounding_box(&curve);
+
+    // compare against dense sampling
+    let mut min = curve[0];
+    let mut max = curve[0];
+    for i in 0..=10_000 {
+        let p = evaluate(&curve, i as f64 / 10_000.);
+        for axis in 0..3 {
+            min[axis] = min[axis].min(p[axis]);
+            max[axis] = max[axis].max(p[axis]);
+        }
+    }
+    assert_abs_diff_eq!(bbox.min, min, epsilon = 1e-6);
+    assert_abs_diff_eq!(bbox.max, max, epsilon = 1e-6);
+    for axis in 0..3 {
+        assert_abs_diff_eq!(evaluate(&curve, bbox.min_t[axis])[axis], bbox.min[axis]);
+        assert_abs_diff_eq!(evaluate(&curve, bbox.max_t[axis])[axis], bbox.max[axis]);
+    }
+}
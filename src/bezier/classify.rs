@@ -0,0 +1,286 @@
+use super::{parametric, parametric_cubic, BezierCurve};
+use cgmath::{BaseFloat, InnerSpace, Point2, Vector2};
+
+/// The type of a 2D cubic bézier curve.
+///
+/// Parameters refer to the whole (infinite) curve and may lie outside [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CubicType<S> {
+    /// A curve with two distinct inflection points, in ascending order.
+    Serpentine { inflections: [S; 2] },
+    /// A curve that intersects itself at two parameters, in ascending order.
+    Loop { double_point: [S; 2] },
+    /// A curve with a cusp, where the inflection points coincide.
+    Cusp { cusp: S },
+    /// A curve with a single inflection point and a cusp at infinity.
+    CuspAtInfinity { inflection: S },
+    /// A degree-elevated quadratic curve.
+    Quadratic,
+    /// A curve with all control points on a line, or all equal.
+    Line,
+}
+
+/// Returns the coefficients (A, B, C) of A t² + B t + C, which is proportional to
+/// B'(t) × B''(t) of a cubic with power form coefficients a t³ + b t² + c t + d.
+pub(crate) fn inflection_polynomial<S>(a: Vector2<S>, b: Vector2<S>, c: Vector2<S>) -> (S, S, S)
+where
+    S: BaseFloat,
+{
+    let three = S::one() + S::one() + S::one();
+    (a.perp_dot(b), a.perp_dot(c), b.perp_dot(c) / three)
+}
+
+/// Classifies a 2D cubic bézier curve.
+///
+/// Near-degenerate cases are decided with a tolerance relative to the size of the curve.
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S; see [`parametric_cubic`]
+///
+/// # Details
+/// This follows "Resolution Independent Curve Rendering using Programmable Graphics Hardware" by
+/// C. Loop and J. Blinn (2005), and "A Geometric Characterization of Parametric Cubic Curves" by
+/// M. Stone and T. DeRose (1989). With the curve in power form B(t) = a t³ + b t² + c t + d,
+/// B'(t) × B''(t) is proportional to A t² + B t + C with A = a × b, B = a × c, C = b × c / 3.
+/// Its roots are the inflection points, and the sign of its discriminant B² - 4AC distinguishes
+/// serpentines from loops. The double point parameters of a loop are the roots of
+/// A² t² + AB t + B² - 3AC.
+pub fn classify_cubic<S>(curve: [Point2<S>; 4]) -> CubicType<S>
+where
+    S: BaseFloat,
+{
+    let (x, y) = parametric_cubic(curve);
+    let a = Vector2::new(x.xxx, y.xxx);
+    let b = Vector2::new(x.xx, y.xx);
+    let c = Vector2::new(x.x, y.x);
+    let (big_a, big_b, big_c) = inflection_polynomial(a, b, c);
+
+    let size = a.magnitude() + b.magnitude() + c.magnitude();
+    let tolerance = S::default_epsilon().sqrt() * size * size;
+    let two = S::one() + S::one();
+    let three = two + S::one();
+    let four = two + two;
+
+    if big_a.abs() <= tolerance {
+        return if big_b.abs() > tolerance {
+            CubicType::CuspAtInfinity {
+                inflection: -big_c / big_b,
+            }
+        } else if big_c.abs() > tolerance {
+            CubicType::Quadratic
+        } else {
+            CubicType::Line
+        };
+    }
+
+    let discriminant = big_b * big_b - four * big_a * big_c;
+    let discriminant_scale = big_b * big_b + (four * big_a * big_c).abs();
+    let ascending = |t1: S, t2: S| [t1.min(t2), t1.max(t2)];
+    if discriminant.abs() <= S::default_epsilon().sqrt() * discriminant_scale {
+        CubicType::Cusp {
+            cusp: -big_b / (two * big_a),
+        }
+    } else if discriminant > S::zero() {
+        let root = discriminant.sqrt();
+        CubicType::Serpentine {
+            inflections: ascending(
+                (-big_b - root) / (two * big_a),
+                (-big_b + root) / (two * big_a),
+            ),
+        }
+    } else {
+        let root = (-three * discriminant).sqrt();
+        CubicType::Loop {
+            double_point: ascending(
+                (-big_b - root) / (two * big_a),
+                (-big_b + root) / (two * big_a),
+            ),
+        }
+    }
+}
+
+/// Returns the parameters in [0, 1] at which a 2D bézier curve has an inflection point, in
+/// ascending order.
+///
+/// These are the roots of B'(t) × B''(t) of odd multiplicity, where the signed curvature changes
+/// sign. Roots of even multiplicity, e.g. at a flat point, are not inflection points.
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+pub fn inflections<S, L>(curve: &L) -> Vec<S>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let n = curve.count();
+    if n < 3 {
+        return Vec::new();
+    }
+    let xy = parametric(curve);
+    let (dx, dy) = (xy[0].derivative(), xy[1].derivative());
+    let (ddx, ddy) = (dx.derivative(), dy.derivative());
+    let cross = dx * ddy - dy * ddx;
+    cross
+        .real_roots_with_multiplicity(S::zero(), S::one())
+        .into_iter()
+        .filter(|root| root.multiplicity % 2 == 1)
+        .map(|root| root.value)
+        .collect()
+}
+
+#[test]
+fn test_classify_cubic() {
+    use super::{evaluate, self_intersect_cubic};
+    use cgmath::assert_abs_diff_eq;
+
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(4., 3.),
+        Point2::new(-1., 3.),
+        Point2::new(3., 0.),
+    ];
+    let si = self_intersect_cubic(curve).unwrap();
+    match classify_cubic(curve) {
+        CubicType::Loop { double_point } => {
+            assert_abs_diff_eq!(double_point[0], si.t1, epsilon = 1e-10);
+            assert_abs_diff_eq!(double_point[1], si.t2, epsilon = 1e-10);
+        }
+        other => panic!("expected loop, got {:?}", other),
+    }
+
+    // the double point may lie outside [0, 1]
+    let curve = [
+        Point2::new(1., 0.),
+        Point2::new(5., 0.),
+        Point2::new(5., 2.),
+        Point2::new(4., 3.),
+    ];
+    match classify_cubic(curve) {
+        CubicType::Loop { double_point } => {
+            assert_abs_diff_eq!(double_point[0], 0., epsilon = 1e-10);
+            assert_abs_diff_eq!(double_point[1], 2., epsilon = 1e-10);
+        }
+        other => panic!("expected loop, got {:?}", other),
+    }
+
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(1., 3.),
+        Point2::new(4., -2.),
+        Point2::new(5., 2.),
+    ];
+    match classify_cubic(curve) {
+        CubicType::Serpentine { inflections } => {
+            assert!(inflections[0] < inflections[1]);
+            let d1: [Vector2<f64>; 3] = super::derive(&curve);
+            let d2: [Vector2<f64>; 2] = super::derive(&d1);
+            for t in inflections.iter() {
+                let cross = evaluate(&d1, *t).perp_dot(evaluate(&d2, *t));
+                assert_abs_diff_eq!(cross, 0., epsilon = 1e-8);
+            }
+        }
+        other => panic!("expected serpentine, got {:?}", other),
+    }
+
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(2., 1.),
+        Point2::new(0., 1.),
+        Point2::new(2., 0.),
+    ];
+    assert_eq!(classify_cubic(curve), CubicType::Cusp { cusp: 0.5 });
+
+    // (t, t³)
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(1. / 3., 0.),
+        Point2::new(2. / 3., 0.),
+        Point2::new(1., 1.),
+    ];
+    assert_eq!(
+        classify_cubic(curve),
+        CubicType::CuspAtInfinity { inflection: 0. }
+    );
+
+    // degree-elevated quadratic (0, 0), (3, 3), (6, 0)
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(2., 2.),
+        Point2::new(4., 2.),
+        Point2::new(6., 0.),
+    ];
+    assert_eq!(classify_cubic(curve), CubicType::Quadratic);
+
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(3., 1.5),
+        Point2::new(-1., -0.5),
+        Point2::new(2., 1.),
+    ];
+    assert_eq!(classify_cubic(curve), CubicType::Line);
+}
+
+#[test]
+fn test_inflections() {
+    use cgmath::{assert_abs_diff_eq, EuclideanSpace};
+
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(1., 3.),
+        Point2::new(4., -2.),
+        Point2::new(5., 2.),
+    ];
+    let inflections_cubic = match classify_cubic(curve) {
+        CubicType::Serpentine { inflections } => inflections,
+        other => panic!("expected serpentine, got {:?}", other),
+    };
+    let expected: Vec<f64> = inflections_cubic
+        .iter()
+        .copied()
+        .filter(|t| (0. ..=1.).contains(t))
+        .collect();
+    let found = inflections(&curve);
+    assert!(!found.is_empty());
+    assert_eq!(found.len(), expected.len());
+    for (t, t_ref) in found.iter().zip(expected.iter()) {
+        assert_abs_diff_eq!(t, t_ref, epsilon = 1e-10);
+    }
+
+    // (t, (2t - 1)³)
+    let curve = vec![
+        Point2::new(0., -1.),
+        Point2::new(1. / 3., 1.),
+        Point2::new(2. / 3., -1.),
+        Point2::new(1., 1.),
+    ];
+    let found = inflections(&curve);
+    assert_eq!(found.len(), 1);
+    assert_abs_diff_eq!(found[0], 0.5, epsilon = 1e-10);
+
+    // (t, (2t - 1)⁴) has a flat point at t = 0.5 where the curvature does not change sign
+    let curve: Vec<Point2<f64>> = (0..5)
+        .map(|i| Point2::new(i as f64 / 4., if i % 2 == 0 { 1. } else { -1. }))
+        .collect();
+    assert!(inflections(&curve).is_empty());
+
+    // arch without inflection points, and a degree-elevated version of it
+    let arch = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(3., 2.),
+        Point2::new(4., 0.),
+    ];
+    assert!(inflections(&arch).is_empty());
+    let elevated: Vec<Point2<f64>> = (0..5)
+        .map(|i| {
+            let t = i as f64 / 4.;
+            let prev = if i > 0 { arch[i - 1] } else { arch[0] };
+            let next = if i < 4 { arch[i] } else { arch[3] };
+            Point2::from_vec(prev.to_vec() * t + next.to_vec() * (1. - t))
+        })
+        .collect();
+    assert!(inflections(&elevated).is_empty());
+
+    // lines have no inflection points
+    assert!(inflections(&[Point2::new(0., 0.), Point2::new(1., 1.)]).is_empty());
+}
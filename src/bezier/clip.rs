@@ -0,0 +1,394 @@
+use super::derive::try_derive;
+use super::subdiv::subdivide_range;
+use super::{evaluate, overlap, subdivide, BezierCurve, CurveIntersections, Intersection, Overlap};
+use crate::CastError;
+use cgmath::{BaseFloat, InnerSpace, Point2, Vector2};
+use std::cmp::Ordering;
+
+/// Maximum number of times the curves are split in half when clipping stalls.
+const CLIP_MAX_DEPTH: usize = 64;
+
+/// Maximum number of clipping steps in one run. Splitting may leave many pairs of ranges to
+/// clip, so this bounds the total work where `CLIP_MAX_DEPTH` only bounds the nesting.
+const CLIP_MAX_ITERATIONS: usize = 1 << 14;
+
+/// Returns the parameter range of curve a whose distance along `axis` lies within the range
+/// spanned by curve b, or None if there is no such range.
+fn axis_range<S>(
+    a: &[Point2<S>],
+    b: &[Point2<S>],
+    origin: Point2<S>,
+    axis: Vector2<S>,
+    slack: S,
+) -> Result<Option<(S, S)>, CastError>
+where
+    S: BaseFloat,
+{
+    let distance = |p: &Point2<S>| (p - origin).dot(axis);
+    let (d_min, d_max) = b
+        .iter()
+        .map(distance)
+        .fold((S::infinity(), S::neg_infinity()), |(min, max), d| {
+            (min.min(d), max.max(d))
+        });
+    let (d_min, d_max) = (d_min - slack, d_max + slack);
+
+    // the convex hull of (i / n, distance(a[i])) bounds the distance function of a
+    let n = S::from(a.len() - 1).ok_or(CastError)?;
+    let hull = a
+        .iter()
+        .enumerate()
+        .map(|(i, p)| Ok((S::from(i).ok_or(CastError)? / n, distance(p))))
+        .collect::<Result<Vec<(S, S)>, CastError>>()?;
+
+    let mut range: Option<(S, S)> = None;
+    let mut include = |t: S| {
+        range = Some(match range {
+            Some((t0, t1)) => (t0.min(t), t1.max(t)),
+            None => (t, t),
+        });
+    };
+    for (i, &(t_i, d_i)) in hull.iter().enumerate() {
+        if d_i >= d_min && d_i <= d_max {
+            include(t_i);
+        }
+        for &(t_j, d_j) in &hull[i + 1..] {
+            for &d in [d_min, d_max].iter() {
+                if (d_i - d) * (d_j - d) < S::zero() {
+                    include(t_i + (t_j - t_i) * (d - d_i) / (d_j - d_i));
+                }
+            }
+        }
+    }
+    Ok(range)
+}
+
+/// Returns the parameter range of curve a that lies within both the fat line of curve b and the
+/// fat line perpendicular to it, or None if they cannot intersect.
+///
+/// The perpendicular fat line bounds the extent of b along its chord. Without it, a curve
+/// touching b tangentially would never be clipped.
+fn clip_range<S>(a: &[Point2<S>], b: &[Point2<S>]) -> Result<Option<(S, S)>, CastError>
+where
+    S: BaseFloat,
+{
+    let origin = b[0];
+    // fall back to the farthest control point if the endpoints coincide
+    let direction = b
+        .iter()
+        .rev()
+        .map(|p| p - origin)
+        .find(|d| d.magnitude2() > S::zero())
+        .unwrap_or_else(Vector2::unit_x)
+        .normalize();
+    let normal = Vector2::new(-direction.y, direction.x);
+
+    let scale = b
+        .iter()
+        .chain(a.iter())
+        .fold(S::zero(), |m, p| m.max(p.x.abs()).max(p.y.abs()));
+    // widened to account for rounding error
+    let slack = scale * S::default_epsilon();
+    let across = match axis_range(a, b, origin, normal, slack)? {
+        Some(range) => range,
+        None => return Ok(None),
+    };
+    let along = match axis_range(a, b, origin, direction, slack)? {
+        Some(range) => range,
+        None => return Ok(None),
+    };
+    let (t0, t1) = (across.0.max(along.0), across.1.min(along.1));
+    Ok(if t0 <= t1 { Some((t0, t1)) } else { None })
+}
+
+/// State of a bézier clipping run.
+struct Clipper<S> {
+    tolerance: S,
+    /// Width below which ranges that stall are not split further, since curves touching
+    /// tangentially cannot be separated more precisely than the square root of the epsilon.
+    resolution: S,
+    /// Number of clipping steps so far.
+    iterations: usize,
+    /// Pairs of parameters on curves a and b.
+    params: Vec<(S, S)>,
+}
+
+impl<S> Clipper<S>
+where
+    S: BaseFloat,
+{
+    /// Clips curve p against curve q and recurses with their roles swapped.
+    ///
+    /// `swapped` is true if p is a part of the original curve b.
+    fn clip(
+        &mut self,
+        p: &[Point2<S>],
+        p_range: (S, S),
+        q: &[Point2<S>],
+        q_range: (S, S),
+        swapped: bool,
+        depth: usize,
+    ) -> Result<(), CastError> {
+        if self.iterations >= CLIP_MAX_ITERATIONS {
+            return Ok(());
+        }
+        self.iterations += 1;
+        let (t0, t1) = match clip_range(p, q)? {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+        let p = subdivide_range(p, t0, t1);
+        let width = p_range.1 - p_range.0;
+        let p_range = (p_range.0 + width * t0, p_range.0 + width * t1);
+
+        let p_width = p_range.1 - p_range.0;
+        let q_width = q_range.1 - q_range.0;
+        // split the longer curve in half if clipping removed less than 20%
+        let stalled = t1 - t0 > S::from(0.8).ok_or(CastError)?;
+        let within = |width: S| width <= self.tolerance || (stalled && width <= self.resolution);
+        if within(p_width) && within(q_width) {
+            let two = S::one() + S::one();
+            let t_p = (p_range.0 + p_range.1) / two;
+            let t_q = (q_range.0 + q_range.1) / two;
+            self.params
+                .push(if swapped { (t_q, t_p) } else { (t_p, t_q) });
+            return Ok(());
+        }
+
+        if stalled {
+            if depth >= CLIP_MAX_DEPTH {
+                return Ok(());
+            }
+            let half = S::from(0.5).ok_or(CastError)?;
+            if p_width > q_width {
+                let (p1, p2) = subdivide(&p, half);
+                let mid = p_range.0 + p_width * half;
+                self.clip(q, q_range, &p1, (p_range.0, mid), !swapped, depth + 1)?;
+                self.clip(q, q_range, &p2, (mid, p_range.1), !swapped, depth + 1)
+            } else {
+                let q = q.to_vec();
+                let (q1, q2) = subdivide(&q, half);
+                let mid = q_range.0 + q_width * half;
+                self.clip(&q1, (q_range.0, mid), &p, p_range, !swapped, depth + 1)?;
+                self.clip(&q2, (mid, q_range.1), &p, p_range, !swapped, depth + 1)
+            }
+        } else {
+            self.clip(q, q_range, &p, p_range, !swapped, depth)
+        }
+    }
+}
+
+/// Finds intersections of two 2D bézier curves of any degree using bézier clipping.
+///
+/// Parameters are refined until they are within `tolerance` of the exact intersection; a
+/// `tolerance` below the machine epsilon (including zero or a negative value) is raised to it.
+/// Tangential intersections are only refined to the square root of the machine epsilon, and the
+/// total number of clipping steps is bounded, so nearly coincident curves may yield approximate
+/// or incomplete results. Intersections are sorted by their parameter on curve a. If the curves
+/// overlap along a segment (see [`overlap`]), the overlap is returned along with the
+/// intersections outside of it. Curves whose control points all coincide have no intersections.
+///
+/// # Panics
+/// - if the number of points or the constants 0.5, 0.8 and 4 used for splitting and merging cannot
+///   be represented by the scalar type S
+///
+/// # Details
+/// Bézier clipping is described in "Curve intersection using Bézier clipping" by T. W. Sederberg
+/// and T. Nishita in *Computer-Aided Design* (1990). The convex hull of the distances of one
+/// curve’s control points to the *fat line* bounding the other curve determines a parameter range
+/// outside of which they cannot intersect. Curves are clipped alternately, and split in half
+/// when clipping converges slowly, e.g. at multiple intersections.
+///
+/// Unlike [`intersect`](super::intersect), this method does not rely on the implicit form, so it
+/// is more robust for nearly tangent curves.
+pub fn intersect_clip<S, L1, L2>(a: &L1, b: &L2, tolerance: S) -> CurveIntersections<S>
+where
+    L1: BezierCurve<Point2<S>>,
+    L2: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let a: Vec<Point2<S>> = (0..a.count()).map(|i| *a.get(i)).collect();
+    let b: Vec<Point2<S>> = (0..b.count()).map(|i| *b.get(i)).collect();
+    if a.len() < 2 || b.len() < 2 {
+        return CurveIntersections::default();
+    }
+
+    let overlap = overlap(&a, &b);
+    let intersections = try_clip_outside(&a, &b, overlap.as_ref(), tolerance)
+        .expect("could not cast point count or clipping constants (0.5, 0.8, 4) to scalar type");
+    CurveIntersections {
+        intersections,
+        overlaps: overlap.into_iter().collect(),
+    }
+}
+
+/// Finds intersections of two curves with at least two points outside of their overlap using
+/// bézier clipping; see [`intersect_clip`].
+pub(crate) fn try_clip_outside<S>(
+    a: &[Point2<S>],
+    b: &[Point2<S>],
+    overlap: Option<&Overlap<S>>,
+    tolerance: S,
+) -> Result<Vec<Intersection<S>>, CastError>
+where
+    S: BaseFloat,
+{
+    // splitting a single point never makes progress
+    if a.iter().all(|p| *p == a[0]) || b.iter().all(|p| *p == b[0]) {
+        return Ok(Vec::new());
+    }
+
+    // parameters cannot be refined beyond the machine epsilon
+    let tolerance = tolerance.max(S::default_epsilon());
+    let sqrt_epsilon = S::default_epsilon().sqrt();
+    let mut clipper = Clipper {
+        tolerance,
+        resolution: sqrt_epsilon,
+        iterations: 0,
+        params: Vec::new(),
+    };
+    let unit = (S::zero(), S::one());
+    let ranges = match overlap {
+        Some(overlap) => overlap.outside_ranges(),
+        None => vec![(unit, unit)],
+    };
+    for (a_range, b_range) in ranges {
+        let a_part = subdivide_range(a, a_range.0, a_range.1);
+        let b_part = subdivide_range(b, b_range.0, b_range.1);
+        clipper.clip(&a_part, a_range, &b_part, b_range, false, 0)?;
+    }
+
+    let mut params = clipper.params;
+    params.sort_by(|p, q| p.partial_cmp(q).unwrap_or(Ordering::Equal));
+
+    // the same intersection may be found on both sides of a split, and tangential intersections
+    // are only determined up to the square root of the machine epsilon
+    let merge_distance = tolerance.max(sqrt_epsilon * S::from(4).ok_or(CastError)?);
+    let mut clusters: Vec<Vec<(S, S)>> = Vec::new();
+    for (t_a, t_b) in params {
+        match clusters.last_mut() {
+            Some(cluster)
+                if cluster.last().is_some_and(|last| {
+                    (t_a - last.0).abs() <= merge_distance && (t_b - last.1).abs() <= merge_distance
+                }) =>
+            {
+                cluster.push((t_a, t_b))
+            }
+            _ => clusters.push(vec![(t_a, t_b)]),
+        }
+    }
+
+    let a = a.to_vec();
+    let b = b.to_vec();
+    let a_derivative: Vec<Vector2<S>> = try_derive(&a)?;
+    let b_derivative: Vec<Vector2<S>> = try_derive(&b)?;
+    let mut intersections = Vec::new();
+    for cluster in clusters {
+        let count = S::from(cluster.len()).ok_or(CastError)?;
+        let (t_a, t_b) = cluster
+            .iter()
+            .fold((S::zero(), S::zero()), |sum, t| (sum.0 + t.0, sum.1 + t.1));
+        let (t_a, t_b) = (t_a / count, t_b / count);
+        // the ends of an overlap are found where the parts outside of it meet the other curve
+        if overlap.is_some_and(|o| o.contains(t_a, t_b, merge_distance)) {
+            continue;
+        }
+        let da = evaluate(&a_derivative, t_a);
+        let db = evaluate(&b_derivative, t_b);
+        let tangential = da.perp_dot(db).abs() <= da.magnitude() * db.magnitude() * sqrt_epsilon;
+        intersections.push(Intersection {
+            t_a,
+            t_b,
+            point: evaluate(&a, t_a),
+            tangential,
+        });
+    }
+    Ok(intersections)
+}
+
+#[test]
+fn test_intersect_clip() {
+    use super::intersect_cubic;
+    use cgmath::assert_abs_diff_eq;
+
+    let curve1 = [
+        Point2::new(0., 0.),
+        Point2::new(5., 11.),
+        Point2::new(7., 2.),
+        Point2::new(16., 0.),
+    ];
+    let curve2 = [
+        Point2::new(1., 6.),
+        Point2::new(2., 0.),
+        Point2::new(14., 10.),
+        Point2::new(11., 1.),
+    ];
+    let ips = intersect_clip(&curve1, &curve2, 1e-10).intersections;
+    let ips_ref = intersect_cubic(curve1, curve2).unwrap().intersections;
+    assert_eq!(ips.len(), 3);
+    assert_eq!(ips.len(), ips_ref.len());
+    for (ip, ip_ref) in ips.iter().zip(ips_ref.iter()) {
+        assert_abs_diff_eq!(ip.t_a, ip_ref.t_a, epsilon = 1e-8);
+        assert_abs_diff_eq!(ip.t_b, ip_ref.t_b, epsilon = 1e-8);
+        assert_abs_diff_eq!(ip.point, ip_ref.point, epsilon = 1e-7);
+    }
+
+    // a quadratic crossing a quartic
+    let quadratic = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(2., 0.),
+    ];
+    let quartic = vec![
+        Point2::new(-1., 1.5),
+        Point2::new(0., -1.),
+        Point2::new(1., 3.),
+        Point2::new(2., -1.),
+        Point2::new(3., 1.5),
+    ];
+    let ips = intersect_clip(&quadratic, &quartic, 1e-10).intersections;
+    assert!(!ips.is_empty());
+    for ip in &ips {
+        assert_abs_diff_eq!(evaluate(&quadratic, ip.t_a), ip.point, epsilon = 1e-8);
+        assert_abs_diff_eq!(evaluate(&quartic, ip.t_b), ip.point, epsilon = 1e-8);
+    }
+    let ips_ref = super::intersect(&quadratic, &quartic)
+        .unwrap()
+        .intersections;
+    assert_eq!(ips.len(), ips_ref.len());
+
+    // arches touching at (2, 1.5)
+    let arch1 = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(3., 2.),
+        Point2::new(4., 0.),
+    ];
+    let arch2 = [
+        Point2::new(0., 3.),
+        Point2::new(1., 1.),
+        Point2::new(3., 1.),
+        Point2::new(4., 3.),
+    ];
+    let ips = intersect_clip(&arch1, &arch2, 1e-10).intersections;
+    assert_eq!(ips.len(), 1);
+    assert_abs_diff_eq!(ips[0].point, Point2::new(2., 1.5), epsilon = 1e-8);
+    assert!(ips[0].tangential);
+
+    // a parabola touching a line, which stalls clipping at every step
+    let parabola = [
+        Point2::new(0., 0.),
+        Point2::new(0.5, 1.),
+        Point2::new(1., 0.),
+    ];
+    let line = [Point2::new(0., 0.5), Point2::new(1., 0.5)];
+    for &tolerance in [1e-9, 0., -1.].iter() {
+        let start = std::time::Instant::now();
+        let ips = intersect_clip(&parabola, &line, tolerance).intersections;
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+        assert_eq!(ips.len(), 1);
+        assert_abs_diff_eq!(ips[0].t_a, 0.5, epsilon = 1e-6);
+        assert_abs_diff_eq!(ips[0].t_b, 0.5, epsilon = 1e-6);
+        assert!(ips[0].tangential);
+    }
+}
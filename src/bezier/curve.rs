@@ -1,6 +1,7 @@
 use cgmath::{BaseNum, EuclideanSpace};
 
 /// A finite list of items (points or vectors) representing a bézier curve. Generalizes over [T; n]
+/// and Vec<T>
 pub trait BezierCurve<T> {
     /// Derivative type.
     type Derivative: BezierCurve<T>;
@@ -84,77 +85,249 @@ where
     }
 }
 
-impl<T> BezierCurve<T> for [T; 0] {
-    type Derivative = Self;
+impl<T, const N: usize> BezierCurve<T> for [T; N]
+where
+    T: Copy,
+{
+    type Derivative = TruncatedArray<T, N>;
+
     fn count(&self) -> usize {
-        0
+        N
     }
+
     fn get(&self, i: usize) -> &T {
         &self[i]
     }
-    fn set(&mut self, _i: usize, _v: T) {}
+
+    fn set(&mut self, i: usize, v: T) {
+        self[i] = v;
+    }
+
     fn clone(&self) -> Self {
-        []
+        *self
     }
+
     fn reduced(&self) -> Self::Derivative {
-        []
+        TruncatedArray {
+            items: *self,
+            len: N.saturating_sub(1),
+        }
     }
 }
 
-impl<T, U> DerivativeSpace<[U; 0]> for [T; 0]
+impl<T, U, const N: usize> DerivativeSpace<[U; N]> for [T; N]
 where
     T: DerivativeSpace<U>,
 {
-    fn from_integral(_: [U; 0]) -> Self {
-        []
+    fn from_integral(this: [U; N]) -> Self {
+        this.map(T::from_integral)
     }
 }
 
-macro_rules! impl_bezier_curve {
-    (m $($n:expr),+) => {
-        $(impl_bezier_curve!($n);)+
-    };
-    ($N:expr) => {
-        impl<T> BezierCurve<T> for [T; $N] where T: Clone + Copy {
-            type Derivative = [T; $N - 1];
+/// An array of which only the first `len` items are in use.
+///
+/// This is the derivative type of `[T; N]`, since `[T; N - 1]` cannot be expressed for arbitrary N
+/// yet. It can be converted back to an array of the right size using [`DerivativeSpace`], so
+/// usually it does not need to be named:
+///
+/// ```
+/// # use cgmath::{Point2, Vector2};
+/// # use algeo::bezier;
+/// let curve = [Point2::new(0., 0.), Point2::new(1., 2.), Point2::new(3., 1.)];
+/// let derivative: [Vector2<f64>; 2] = bezier::derive(&curve);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TruncatedArray<T, const N: usize> {
+    items: [T; N],
+    len: usize,
+}
 
-            fn count(&self) -> usize {
-                $N
-            }
+impl<T, const N: usize> TruncatedArray<T, N> {
+    /// Creates a new truncated array using the first `len` items.
+    ///
+    /// # Panics
+    /// - if len is greater than N
+    pub fn new(items: [T; N], len: usize) -> Self {
+        assert!(len <= N, "truncated array length out of bounds");
+        TruncatedArray { items, len }
+    }
 
-            fn get(&self, i: usize) -> &T {
-                &self[i]
-            }
+    /// Returns the items in use.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+}
 
-            fn set(&mut self, i: usize, v: T) {
-                self[i] = v;
-            }
+impl<T, const N: usize> BezierCurve<T> for TruncatedArray<T, N>
+where
+    T: Copy,
+{
+    type Derivative = Self;
 
-            fn clone(&self) -> Self {
-                *self
-            }
+    fn count(&self) -> usize {
+        self.len
+    }
 
-            fn reduced(&self) -> Self::Derivative {
-                let mut res = [self[0]; $N - 1];
-                for i in 1..($N - 1) {
-                    res[i] = self[i];
-                }
-                res
-            }
+    fn get(&self, i: usize) -> &T {
+        &self.as_slice()[i]
+    }
+
+    fn set(&mut self, i: usize, v: T) {
+        self.items[..self.len][i] = v;
+    }
+
+    fn clone(&self) -> Self {
+        *self
+    }
+
+    fn reduced(&self) -> Self::Derivative {
+        TruncatedArray {
+            items: self.items,
+            len: self.len.saturating_sub(1),
         }
-        impl<T, U> DerivativeSpace<[U; $N]> for [T; $N]
-        where
-            T: DerivativeSpace<U> + Copy,
-            U: Copy,
-        {
-            fn from_integral(this: [U; $N]) -> Self {
-                let mut res = [T::from_integral(this[0]); $N];
-                for i in 1..$N {
-                    res[i] = T::from_integral(this[i]);
-                }
-                res
-            }
+    }
+}
+
+impl<T, U, const N: usize> DerivativeSpace<TruncatedArray<U, N>> for TruncatedArray<T, N>
+where
+    T: DerivativeSpace<U>,
+{
+    fn from_integral(this: TruncatedArray<U, N>) -> Self {
+        TruncatedArray {
+            items: this.items.map(T::from_integral),
+            len: this.len,
         }
-    };
+    }
+}
+
+/// Converts a truncated array back to an array of its length.
+///
+/// The length is only known at runtime, since `[T; N - 1]` cannot be expressed for arbitrary N
+/// yet, so a mismatch is not caught at compile time.
+impl<T, U, const N: usize, const M: usize> DerivativeSpace<TruncatedArray<U, N>> for [T; M]
+where
+    T: DerivativeSpace<U>,
+    U: Copy,
+{
+    /// # Panics
+    /// - if the length of the truncated array is not M
+    fn from_integral(this: TruncatedArray<U, N>) -> Self {
+        assert_eq!(
+            this.len, M,
+            "truncated array length does not match array size"
+        );
+        std::array::from_fn(|i| T::from_integral(this.items[i]))
+    }
+}
+
+/// Heap-backed curve, for when the degree is only known at runtime.
+impl<T> BezierCurve<T> for Vec<T>
+where
+    T: Clone,
+{
+    type Derivative = Self;
+
+    fn count(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, i: usize) -> &T {
+        &self[i]
+    }
+
+    fn set(&mut self, i: usize, v: T) {
+        self[i] = v;
+    }
+
+    fn clone(&self) -> Self {
+        self.to_vec()
+    }
+
+    fn reduced(&self) -> Self::Derivative {
+        self[..self.len().saturating_sub(1)].to_vec()
+    }
+}
+
+impl<T, U> DerivativeSpace<Vec<U>> for Vec<T>
+where
+    T: DerivativeSpace<U>,
+{
+    fn from_integral(this: Vec<U>) -> Self {
+        this.into_iter().map(T::from_integral).collect()
+    }
+}
+
+#[test]
+fn test_high_degree() {
+    use super::{derive, evaluate, subdivide};
+    use cgmath::assert_abs_diff_eq;
+    use cgmath::{Point2, Vector2};
+
+    // degree 8 curve with all control points on a line, so it is a (reparametrized) line
+    let mut curve = [Point2::new(0., 0.); 9];
+    for (i, p) in curve.iter_mut().enumerate() {
+        *p = Point2::new(i as f64, 2. * i as f64);
+    }
+
+    for t in [0., 0.3, 0.5, 0.9, 1.].iter() {
+        assert_abs_diff_eq!(
+            evaluate(&curve, *t),
+            Point2::new(8. * t, 16. * t),
+            epsilon = 1e-10
+        );
+    }
+
+    let derivative: [Vector2<f64>; 8] = derive(&curve);
+    let second_derivative: [Vector2<f64>; 7] = derive(&derivative);
+    for t in [0., 0.3, 0.5, 0.9, 1.].iter() {
+        assert_abs_diff_eq!(
+            evaluate(&derivative, *t),
+            Vector2::new(8., 16.),
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(
+            evaluate(&second_derivative, *t),
+            Vector2::new(0., 0.),
+            epsilon = 1e-10
+        );
+    }
+
+    let (a, b) = subdivide(&curve, 0.25);
+    assert_abs_diff_eq!(evaluate(&a, 1.), Point2::new(2., 4.), epsilon = 1e-10);
+    assert_abs_diff_eq!(evaluate(&b, 0.5), evaluate(&curve, 0.625), epsilon = 1e-10);
+}
+
+#[test]
+fn test_vec() {
+    use super::{derive, evaluate, hull_arclen_bounds, subdivide};
+    use cgmath::assert_abs_diff_eq;
+    use cgmath::{Point2, Vector2};
+
+    let array = [
+        Point2::new(0., 2.),
+        Point2::new(4., 3.),
+        Point2::new(6., 0.),
+        Point2::new(9., 4.),
+        Point2::new(7., 8.),
+    ];
+    let curve = array.to_vec();
+
+    let derivative: Vec<Vector2<f64>> = derive(&curve);
+    let array_derivative: [Vector2<f64>; 4] = derive(&array);
+    assert_eq!(derivative.len(), 4);
+
+    let (a, b) = subdivide(&curve, 0.4);
+    let (array_a, array_b) = subdivide(&array, 0.4);
+
+    for t in [0., 0.3, 0.5, 0.9, 1.].iter() {
+        assert_abs_diff_eq!(evaluate(&curve, *t), evaluate(&array, *t));
+        assert_abs_diff_eq!(evaluate(&derivative, *t), evaluate(&array_derivative, *t));
+        assert_abs_diff_eq!(evaluate(&a, *t), evaluate(&array_a, *t));
+        assert_abs_diff_eq!(evaluate(&b, *t), evaluate(&array_b, *t));
+    }
+
+    let (lower, upper) = hull_arclen_bounds(&curve);
+    let (array_lower, array_upper) = hull_arclen_bounds(&array);
+    assert_abs_diff_eq!(lower, array_lower);
+    assert_abs_diff_eq!(upper, array_upper);
 }
-impl_bezier_curve!(m 1, 2, 3, 4, 5, 6);
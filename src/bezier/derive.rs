@@ -1,4 +1,5 @@
 use super::{BezierCurve, DerivativeSpace};
+use crate::CastError;
 use cgmath::num_traits::NumCast;
 use std::ops;
 
@@ -6,6 +7,7 @@ use std::ops;
 ///
 /// # Panics
 /// - if the number of points cannot be represented by the scalar type S
+/// - if the result is an array whose size is not one less than the number of points
 ///
 /// # Examples
 /// ```
@@ -36,6 +38,18 @@ use std::ops;
 /// );
 /// ```
 pub fn derive<S, P, V, L, R>(points: &L) -> R
+where
+    L: BezierCurve<P>,
+    P: ops::Sub<P, Output = V> + Clone,
+    V: ops::Mul<S, Output = V>,
+    S: NumCast + Clone,
+    R: DerivativeSpace<L::Derivative> + BezierCurve<V>,
+{
+    try_derive(points).expect("could not cast point count to scalar type")
+}
+
+/// Returns the derivative of a bézier curve; see [`derive`].
+pub(crate) fn try_derive<S, P, V, L, R>(points: &L) -> Result<R, CastError>
 where
     L: BezierCurve<P>,
     P: ops::Sub<P, Output = V> + Clone,
@@ -44,15 +58,14 @@ where
     R: DerivativeSpace<L::Derivative> + BezierCurve<V>,
 {
     let mut derivative = R::from_integral(points.reduced());
-    let n: S =
-        NumCast::from(points.count() - 1).expect("could not cast point count to scalar type");
+    let n: S = NumCast::from(points.count() - 1).ok_or(CastError)?;
     for i in 0..(points.count() - 1) {
         derivative.set(
             i,
             (points.get(i + 1).clone() - points.get(i).clone()) * n.clone(),
         );
     }
-    derivative
+    Ok(derivative)
 }
 
 #[test]
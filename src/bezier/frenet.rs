@@ -0,0 +1,398 @@
+use super::{evaluate, BezierCurve};
+use cgmath::{BaseFloat, EuclideanSpace, InnerSpace, Point2, Point3, Vector3};
+
+/// The shape of a curve in the neighborhood of a parameter value.
+///
+/// Where the first derivative vanishes (e.g. at an endpoint with coincident control points),
+/// quantities are taken as the limit towards t from inside the curve, using the first nonzero
+/// derivative of order m and the first derivative of order k not parallel to it. Locally, the
+/// curve then looks like `u ↦ (u, b·u^(k/m))` in a scaled coordinate system.
+struct LocalShape<S, V> {
+    /// Derivatives of order 1 to n, as seen when leaving t into the curve. At t = 1, the curve is
+    /// left backwards, so odd derivatives are negated there.
+    derivatives: Vec<V>,
+    /// Whether the curve is left backwards.
+    backwards: bool,
+    /// Squared magnitude below which vectors are treated as zero.
+    threshold: S,
+}
+
+impl<S, V> LocalShape<S, V>
+where
+    V: InnerSpace<Scalar = S>,
+    S: BaseFloat,
+{
+    fn new<P, L>(points: &L, t: S) -> Self
+    where
+        L: BezierCurve<P>,
+        P: EuclideanSpace<Scalar = S, Diff = V>,
+    {
+        let backwards = t >= S::one();
+        let mut hodograph: Vec<V> = (0..points.count())
+            .map(|i| points.get(i).to_vec())
+            .collect();
+        let mut derivatives = Vec::new();
+        let mut scale = S::zero();
+        while hodograph.len() > 1 {
+            let n = S::from(hodograph.len() - 1).unwrap();
+            hodograph = hodograph.windows(2).map(|w| (w[1] - w[0]) * n).collect();
+            let mut d = evaluate(&hodograph, t);
+            if backwards && derivatives.len() % 2 == 0 {
+                d = d * -S::one();
+            }
+            scale = scale.max(d.magnitude2());
+            derivatives.push(d);
+        }
+
+        LocalShape {
+            derivatives,
+            backwards,
+            threshold: scale * S::epsilon(),
+        }
+    }
+
+    /// Returns the derivative of the given order.
+    fn d(&self, order: usize) -> V {
+        self.derivatives[order - 1]
+    }
+
+    /// Returns the order m of the first nonzero derivative.
+    fn tangent_order(&self) -> Option<usize> {
+        let threshold = self.threshold;
+        (1..=self.derivatives.len()).find(|i| self.d(*i).magnitude2() > threshold)
+    }
+
+    /// Returns the component of v perpendicular to u.
+    fn perpendicular(v: V, u: V) -> V {
+        v - u * (v.dot(u) / u.magnitude2())
+    }
+
+    /// Returns the order k of the first derivative not parallel to the derivative of order m.
+    fn bending_order(&self, m: usize) -> Option<usize> {
+        let threshold = self.threshold;
+        ((m + 1)..=self.derivatives.len())
+            .find(|i| Self::perpendicular(self.d(*i), self.d(m)).magnitude2() > threshold)
+    }
+
+    /// Returns the unit tangent in the direction of travel.
+    fn tangent(&self) -> Option<V> {
+        let m = self.tangent_order()?;
+        let tangent = self.d(m).normalize();
+        Some(if self.backwards {
+            tangent * -S::one()
+        } else {
+            tangent
+        })
+    }
+
+    /// Returns the unit vector pointing to the side the curve bends towards.
+    fn normal(&self) -> Option<V> {
+        let m = self.tangent_order()?;
+        let k = self.bending_order(m)?;
+        Some(Self::perpendicular(self.d(k), self.d(m)).normalize())
+    }
+
+    /// Returns the unsigned curvature.
+    fn curvature(&self) -> S {
+        let zero = S::zero();
+        let m = match self.tangent_order() {
+            Some(m) => m,
+            None => return zero,
+        };
+        let k = match self.bending_order(m) {
+            Some(k) => k,
+            None => return zero,
+        };
+
+        if k < 2 * m {
+            S::infinity()
+        } else if k > 2 * m {
+            zero
+        } else {
+            // u = a·h^m, y = b·h^2m = (b / a²)·u², which has curvature 2b/a² at u = 0
+            let a = self.d(m).magnitude() / factorial(m);
+            let b = Self::perpendicular(self.d(k), self.d(m)).magnitude() / factorial(k);
+            (b + b) / (a * a)
+        }
+    }
+}
+
+fn factorial<S>(n: usize) -> S
+where
+    S: BaseFloat,
+{
+    (1..=n).fold(S::one(), |acc, i| acc * S::from(i).unwrap())
+}
+
+/// Returns the unit tangent of a bézier curve at t, pointing in the direction of travel.
+///
+/// If the first derivative is zero at t, higher derivatives are used instead. Returns None if the
+/// curve is a single point.
+pub fn tangent<S, P, L>(points: &L, t: S) -> Option<P::Diff>
+where
+    L: BezierCurve<P>,
+    P: EuclideanSpace<Scalar = S>,
+    P::Diff: InnerSpace<Scalar = S>,
+    S: BaseFloat,
+{
+    LocalShape::new(points, t).tangent()
+}
+
+/// Returns the principal unit normal of a bézier curve at t, i.e. the unit vector perpendicular
+/// to the tangent, pointing to the side that the curve bends towards.
+///
+/// If the first derivative is zero at t, higher derivatives are used instead. Returns None if the
+/// curve is straight around t.
+pub fn normal<S, P, L>(points: &L, t: S) -> Option<P::Diff>
+where
+    L: BezierCurve<P>,
+    P: EuclideanSpace<Scalar = S>,
+    P::Diff: InnerSpace<Scalar = S>,
+    S: BaseFloat,
+{
+    LocalShape::new(points, t).normal()
+}
+
+/// Returns the (unsigned) curvature of a bézier curve at t.
+///
+/// If the first derivative is zero at t, the curvature is the limit towards t, which may be zero
+/// or infinite.
+pub fn curvature<S, P, L>(points: &L, t: S) -> S
+where
+    L: BezierCurve<P>,
+    P: EuclideanSpace<Scalar = S>,
+    P::Diff: InnerSpace<Scalar = S>,
+    S: BaseFloat,
+{
+    LocalShape::new(points, t).curvature()
+}
+
+/// Returns the signed curvature of a 2D bézier curve at t.
+///
+/// The curvature is positive where the curve turns counterclockwise (i.e. left in a y-up
+/// coordinate system). If the first derivative is zero at t, the curvature is the limit towards
+/// t, which may be zero or infinite.
+pub fn signed_curvature<S, L>(points: &L, t: S) -> S
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let shape = LocalShape::new(points, t);
+    let curvature = shape.curvature();
+    let m = match shape.tangent_order() {
+        Some(m) => m,
+        None => return curvature,
+    };
+    let k = match shape.bending_order(m) {
+        Some(k) => k,
+        None => return curvature,
+    };
+
+    let turns_left = shape.d(m).perp_dot(shape.d(k)) > S::zero();
+    if turns_left != shape.backwards {
+        curvature
+    } else {
+        -curvature
+    }
+}
+
+/// Returns the torsion of a 3D bézier curve at t.
+///
+/// If the first derivative is zero at t, the torsion is the limit towards t, which may be zero or
+/// infinite. Where the curve is locally planar, the torsion is zero.
+pub fn torsion<S, L>(points: &L, t: S) -> S
+where
+    L: BezierCurve<Point3<S>>,
+    S: BaseFloat,
+{
+    let shape = LocalShape::new(points, t);
+    let zero = S::zero();
+    let m = match shape.tangent_order() {
+        Some(m) => m,
+        None => return zero,
+    };
+    let k = match shape.bending_order(m) {
+        Some(k) => k,
+        None => return zero,
+    };
+
+    // first derivative of order l leaving the osculating plane
+    let binormal = shape.d(m).cross(shape.d(k)).normalize();
+    let threshold = shape.threshold;
+    let l = ((k + 1)..=shape.derivatives.len()).find(|i| {
+        let out_of_plane = shape.d(*i).dot(binormal);
+        out_of_plane * out_of_plane > threshold
+    });
+    let l = match l {
+        Some(l) => l,
+        None => return zero,
+    };
+
+    // with u = a·h^m, y = b·h^k, z = c·h^l, the torsion behaves like u^((l - k - m) / m)
+    let c = shape.d(l).dot(binormal) / factorial(l);
+    if l < k + m {
+        c.signum() * S::infinity()
+    } else if l > k + m {
+        zero
+    } else {
+        let a = shape.d(m).magnitude() / factorial(m);
+        let b = LocalShape::perpendicular(shape.d(k), shape.d(m)).magnitude() / factorial(k);
+        let p = S::from(k).unwrap() / S::from(m).unwrap();
+        (p + S::one()) / (p - S::one()) * c / (b * a)
+    }
+}
+
+/// A Frenet–Serret frame: orthonormal vectors describing the local orientation of a 3D curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrenetFrame<S> {
+    /// Unit tangent in the direction of travel.
+    pub tangent: Vector3<S>,
+    /// Principal unit normal, pointing to the side the curve bends towards.
+    pub normal: Vector3<S>,
+    /// Unit binormal, the cross product of tangent and normal.
+    pub binormal: Vector3<S>,
+}
+
+/// Returns the Frenet–Serret frame of a 3D bézier curve at t.
+///
+/// If the first derivative is zero at t, higher derivatives are used instead. Returns None if the
+/// curve is straight around t, since the normal is not defined there.
+pub fn frenet_frame<S, L>(points: &L, t: S) -> Option<FrenetFrame<S>>
+where
+    L: BezierCurve<Point3<S>>,
+    S: BaseFloat,
+{
+    let shape = LocalShape::new(points, t);
+    let tangent = shape.tangent()?;
+    let normal = shape.normal()?;
+    Some(FrenetFrame {
+        tangent,
+        normal,
+        binormal: tangent.cross(normal),
+    })
+}
+
+#[test]
+fn test_frenet() {
+    use cgmath::assert_abs_diff_eq;
+    use cgmath::{Vector2, Zero};
+
+    // circle of radius 2 approximated by a cubic
+    let k = 4. / 3. * (2_f64.sqrt() - 1.);
+    let arc = [
+        Point2::new(2., 0.),
+        Point2::new(2., 2. * k),
+        Point2::new(2. * k, 2.),
+        Point2::new(0., 2.),
+    ];
+    assert_abs_diff_eq!(tangent(&arc, 0.).unwrap(), Vector2::new(0., 1.));
+    assert_abs_diff_eq!(tangent(&arc, 1.).unwrap(), Vector2::new(-1., 0.));
+    assert_abs_diff_eq!(normal(&arc, 0.).unwrap(), Vector2::new(-1., 0.));
+    assert_abs_diff_eq!(normal(&arc, 1.).unwrap(), Vector2::new(0., -1.));
+    for i in 0..=10 {
+        let t = i as f64 / 10.;
+        assert_abs_diff_eq!(curvature(&arc, t), 0.5, epsilon = 0.03);
+        assert_abs_diff_eq!(signed_curvature(&arc, t), 0.5, epsilon = 0.03);
+    }
+
+    let reversed = [arc[3], arc[2], arc[1], arc[0]];
+    assert_abs_diff_eq!(signed_curvature(&reversed, 0.), -signed_curvature(&arc, 1.));
+    assert_abs_diff_eq!(signed_curvature(&reversed, 1.), -signed_curvature(&arc, 0.));
+
+    // coincident control points at both ends of a straight line
+    let line = [
+        Point2::new(0., 0.),
+        Point2::new(0., 0.),
+        Point2::new(2., 4.),
+        Point2::new(2., 4.),
+    ];
+    assert_abs_diff_eq!(
+        tangent(&line, 0.).unwrap(),
+        Vector2::new(1., 2.).normalize()
+    );
+    assert_abs_diff_eq!(
+        tangent(&line, 1.).unwrap(),
+        Vector2::new(1., 2.).normalize()
+    );
+    assert_eq!(normal(&line, 0.), None);
+    assert_eq!(curvature(&line, 0.), 0.);
+    assert_eq!(curvature(&line, 1.), 0.);
+
+    // coincident control points at the start only: the curve has a singular point there
+    let cusp = [
+        Point2::new(0., 0.),
+        Point2::new(0., 0.),
+        Point2::new(2., 0.),
+        Point2::new(2., 2.),
+    ];
+    assert_abs_diff_eq!(tangent(&cusp, 0.).unwrap(), Vector2::new(1., 0.));
+    assert_abs_diff_eq!(normal(&cusp, 0.).unwrap(), Vector2::new(0., 1.));
+    assert_eq!(signed_curvature(&cusp, 0.), f64::INFINITY);
+
+    // quadratic with a doubled control point at the start, which is a straight line
+    let quadratic = [
+        Point2::new(0., 0.),
+        Point2::new(0., 0.),
+        Point2::new(1., 1.),
+    ];
+    assert_abs_diff_eq!(
+        tangent(&quadratic, 0.).unwrap(),
+        Vector2::new(1., 1.).normalize()
+    );
+    assert_eq!(curvature(&quadratic, 0.), 0.);
+
+    // (2t, 2t²) has curvature 8 / (4 + 16t²)^(3/2), turning left
+    let quadratic = [
+        Point2::new(0., 0.),
+        Point2::new(1., 0.),
+        Point2::new(2., 2.),
+    ];
+    for i in 0..=10 {
+        let t = i as f64 / 10.;
+        let expected = 8. / (4. + 16. * t * t).powf(1.5);
+        assert_abs_diff_eq!(curvature(&quadratic, t), expected, epsilon = 1e-12);
+        assert_abs_diff_eq!(signed_curvature(&quadratic, t), expected, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            tangent(&quadratic, t).unwrap(),
+            Vector2::new(1., 2. * t).normalize(),
+            epsilon = 1e-12
+        );
+    }
+
+    let point = [Point2::new(1., 1.); 4];
+    assert_eq!(tangent(&point, 0.5), None);
+    assert_eq!(curvature(&point, 0.5), 0.);
+
+    // helix segment x = cos θ, y = sin θ, z = θ / 2 approximated around θ = 0 by its Taylor
+    // expansion up to the cubic term; curvature and torsion at θ = 0 are 0.8 and 0.4
+    let taylor = [
+        Point3::new(1., 0., 0.),
+        Point3::new(1., 1. / 3., 1. / 6.),
+        Point3::new(1. - 1. / 6., 2. / 3., 1. / 3.),
+        Point3::new(1. - 1. / 2., 1. - 1. / 6., 1. / 2.),
+    ];
+    assert_abs_diff_eq!(curvature(&taylor, 0.), 0.8, epsilon = 1e-10);
+    assert_abs_diff_eq!(torsion(&taylor, 0.), 0.4, epsilon = 1e-10);
+    let frame = frenet_frame(&taylor, 0.).unwrap();
+    assert_abs_diff_eq!(frame.tangent, Vector3::new(0., 2., 1.).normalize());
+    assert_abs_diff_eq!(frame.normal, Vector3::new(-1., 0., 0.));
+    assert_abs_diff_eq!(frame.binormal, Vector3::new(0., -1., 2.).normalize());
+
+    // doubled endpoints make the parametrization singular there
+    let taylor_degenerate = [
+        taylor[0], taylor[0], taylor[1], taylor[2], taylor[3], taylor[3],
+    ];
+    let frame = frenet_frame(&taylor_degenerate, 1.).unwrap();
+    assert_abs_diff_eq!(frame.tangent.dot(frame.normal), 0., epsilon = 1e-10);
+    assert_abs_diff_eq!(frame.binormal.magnitude(), 1., epsilon = 1e-10);
+
+    let planar = [
+        Point3::new(0_f64, 0., 1.),
+        Point3::new(1., 2., 1.),
+        Point3::new(3., 0., 1.),
+        Point3::new(4., 1., 1.),
+    ];
+    assert_eq!(torsion(&planar, 0.3), 0.);
+    assert!(torsion(&planar, 1.).is_finite());
+    assert!(!frenet_frame(&planar, 0.5).unwrap().binormal.is_zero());
+}
@@ -1,4 +1,6 @@
-use crate::polynomial::{Poly1x2d, Poly3x2d};
+use super::BezierCurve;
+use crate::polynomial::{try_binomial, MultiPolynomial, Poly1x2d, Poly2x2d, Poly3x2d};
+use crate::CastError;
 use cgmath::{BaseFloat, Point2};
 
 /// Returns the determinant of the following matrix:
@@ -30,17 +32,13 @@ where
     a - b + c
 }
 
-fn binom(n: usize, k: usize) -> f64 {
-    (1..=k).map(|i| (n + 1 - i) as f64 / i as f64).product()
-}
-
-fn impl_l<S, const N: usize>(curve: [Point2<S>; N], i: usize, j: usize) -> Poly1x2d<S>
+fn impl_l<S>(curve: &[Point2<S>], i: usize, j: usize) -> Result<Poly1x2d<S>, CastError>
 where
     S: BaseFloat,
 {
     let det = impl_l_det(curve[i], curve[j]);
-    let n = N - 1; // degree
-    det * S::from(binom(n, i) * binom(n, j)).unwrap()
+    let n = curve.len() - 1; // degree
+    Ok(det * (try_binomial::<S>(n, i)? * try_binomial::<S>(n, j)?))
 }
 
 /// Expands the determinant of a row major matrix of polynomials.
@@ -54,10 +52,85 @@ where
     a - b + c
 }
 
+/// Expands the determinant of a square row major matrix of polynomials.
+///
+/// Minors are shared between cofactor expansions, which takes O(2ⁿ n) polynomial products
+/// instead of O(n!).
+fn expand_det<S>(matrix: &[Vec<MultiPolynomial<S>>]) -> MultiPolynomial<S>
+where
+    S: BaseFloat,
+{
+    let n = matrix.len();
+    // minors[cols] is the determinant of the first |cols| rows and the columns in the bit set cols
+    let mut minors = vec![MultiPolynomial::default(); 1 << n];
+    minors[0] = MultiPolynomial::constant(S::one());
+    for cols in 1..minors.len() {
+        let row = cols.count_ones() as usize - 1;
+        let mut det = MultiPolynomial::default();
+        let mut position = 0;
+        for (col, entry) in matrix[row].iter().enumerate() {
+            if cols & (1 << col) == 0 {
+                continue;
+            }
+            let term = entry.clone() * minors[cols & !(1 << col)].clone();
+            det = if (row + position) % 2 == 1 {
+                det - term
+            } else {
+                det + term
+            };
+            position += 1;
+        }
+        minors[cols] = det;
+    }
+    minors.pop().unwrap()
+}
+
+/// Returns an implicit function for a 2D line segment (i.e. a linear bézier curve).
+///
+/// The line is located at f(x, y) = 0.
+pub fn implicit_line<S>(curve: [Point2<S>; 2]) -> Poly1x2d<S>
+where
+    S: BaseFloat,
+{
+    impl_l_det(curve[1], curve[0])
+}
+
+/// Returns an implicit function for a 2D quadratic bézier curve.
+///
+/// The curve is located at f(x, y) = 0.
+///
+/// # Panics
+/// - if the binomial coefficients cannot be represented by the scalar type S
+///
+/// # Details
+/// Curve implicitization is implemented using the method outlined in chapter 17 of "Computer
+/// Aided Geometric Design" by Thomas W. Sederberg.
+pub fn implicit_quadratic<S>(curve: [Point2<S>; 3]) -> Poly2x2d<S>
+where
+    S: BaseFloat,
+{
+    try_implicit_quadratic(curve).expect("could not cast binomial coefficients to scalar type")
+}
+
+/// Returns an implicit function for a 2D quadratic bézier curve; see [`implicit_quadratic`].
+pub(crate) fn try_implicit_quadratic<S>(curve: [Point2<S>; 3]) -> Result<Poly2x2d<S>, CastError>
+where
+    S: BaseFloat,
+{
+    let l21 = impl_l(&curve, 2, 1)?;
+    let l20 = impl_l(&curve, 2, 0)?;
+    let l10 = impl_l(&curve, 1, 0)?;
+
+    Ok(l21 * l10 - l20 * l20)
+}
+
 /// Returns an implicit function for a 2D cubic bézier curve.
 ///
 /// The curve is located at f(x, y) = 0.
 ///
+/// # Panics
+/// - if the binomial coefficients cannot be represented by the scalar type S
+///
 /// # Details
 /// Curve implicitization is implemented using the method outlined in chapter 17 of "Computer
 /// Aided Geometric Design" by Thomas W. Sederberg.
@@ -65,14 +138,138 @@ pub fn implicit_cubic<S>(curve: [Point2<S>; 4]) -> Poly3x2d<S>
 where
     S: BaseFloat,
 {
-    let l32 = impl_l(curve, 3, 2);
-    let l31 = impl_l(curve, 3, 1);
-    let l30 = impl_l(curve, 3, 0);
-    let l21 = impl_l(curve, 2, 1);
-    let l20 = impl_l(curve, 2, 0);
-    let l10 = impl_l(curve, 1, 0);
+    try_implicit_cubic(curve).expect("could not cast binomial coefficients to scalar type")
+}
+
+/// Returns an implicit function for a 2D cubic bézier curve; see [`implicit_cubic`].
+pub(crate) fn try_implicit_cubic<S>(curve: [Point2<S>; 4]) -> Result<Poly3x2d<S>, CastError>
+where
+    S: BaseFloat,
+{
+    let l32 = impl_l(&curve, 3, 2)?;
+    let l31 = impl_l(&curve, 3, 1)?;
+    let l30 = impl_l(&curve, 3, 0)?;
+    let l21 = impl_l(&curve, 2, 1)?;
+    let l20 = impl_l(&curve, 2, 0)?;
+    let l10 = impl_l(&curve, 1, 0)?;
+
+    Ok(expand_det3([
+        [l32, l31, l30],
+        [l31, l30 + l21, l20],
+        [l30, l20, l10],
+    ]))
+}
+
+/// Returns an implicit function for a 2D bézier curve of any degree.
+///
+/// The curve is located at f(x, y) = 0, where x and y are the variables with indices 0 and 1. The
+/// degree of f equals the degree of the curve.
+///
+/// # Panics
+/// - if the curve has fewer than two control points
+/// - if the binomial coefficients cannot be represented by the scalar type S
+///
+/// # Details
+/// Curve implicitization is implemented using the method outlined in chapter 17 of "Computer
+/// Aided Geometric Design" by Thomas W. Sederberg. f is the determinant of the n×n Bézout matrix,
+/// which generalizes the matrix in [`implicit_cubic`].
+pub fn implicit_curve<S, L>(curve: &L) -> MultiPolynomial<S>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let curve: Vec<Point2<S>> = (0..curve.count()).map(|i| *curve.get(i)).collect();
+    assert!(curve.len() >= 2, "curve has fewer than two control points");
+    try_implicit_curve(&curve).expect("could not cast binomial coefficients to scalar type")
+}
+
+/// Returns an implicit function for a 2D bézier curve of degree ≥ 1; see [`implicit_curve`].
+pub(crate) fn try_implicit_curve<S>(curve: &[Point2<S>]) -> Result<MultiPolynomial<S>, CastError>
+where
+    S: BaseFloat,
+{
+    let n = curve.len() - 1; // degree
+    let mut matrix = vec![vec![MultiPolynomial::default(); n]; n];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            for k in 0..=i.min(j) {
+                let l = i + j + 1 - k;
+                if l <= n {
+                    *entry = entry.clone() + MultiPolynomial::from(impl_l(curve, l, k)?);
+                }
+            }
+        }
+    }
+    Ok(expand_det(&matrix))
+}
+
+/// Solves c1·a + c2·b = -c for constants c1 and c2, where a, b, c are linear forms vanishing at
+/// the same point (so they span a two-dimensional space).
+fn solve_pencil<S>(a: Poly1x2d<S>, b: Poly1x2d<S>, c: Poly1x2d<S>) -> Option<(S, S)>
+where
+    S: BaseFloat,
+{
+    let det = a.x * b.y - b.x * a.y;
+    if det == S::zero() {
+        return None;
+    }
+    let c1 = (b.x * c.y - c.x * b.y) / det;
+    let c2 = (c.x * a.y - a.x * c.y) / det;
+    Some((c1, c2))
+}
+
+/// Returns the parameter value of a point on a 2D cubic bézier curve.
+///
+/// The point must lie on the curve (i.e. its implicit equation must be zero); for other points,
+/// the result is meaningless. If the point is on the curve’s algebraic extension, t may lie
+/// outside [0, 1]. Returns None if the curve is degenerate, if the parameter cannot be determined
+/// (e.g. at a self-intersection), or if the binomial coefficients cannot be represented by S.
+///
+/// # Details
+/// Inversion is implemented using the method outlined in chapter 17 of "Computer Aided Geometric
+/// Design" by Thomas W. Sederberg. For points on the curve, the matrix in [`implicit_cubic`] has
+/// the null vector (t², t(1 - t), (1 - t)²). Combining its rows to cancel out one column yields
+/// t as a ratio of two linear forms in x and y.
+pub fn invert_cubic<S>(curve: [Point2<S>; 4], point: Point2<S>) -> Option<S>
+where
+    S: BaseFloat,
+{
+    let l32 = impl_l(&curve, 3, 2).ok()?;
+    let l31 = impl_l(&curve, 3, 1).ok()?;
+    let l30 = impl_l(&curve, 3, 0).ok()?;
+    let l21 = impl_l(&curve, 2, 1).ok()?;
+    let l20 = impl_l(&curve, 2, 0).ok()?;
+    let l10 = impl_l(&curve, 1, 0).ok()?;
+    let eval = |l: Poly1x2d<S>| l.eval(point.x, point.y);
 
-    expand_det3([[l32, l31, l30], [l31, l30 + l21, l20], [l30, l20, l10]])
+    // the last column (l30, l20, l10) consists of lines through the first control point, and the
+    // first column (l32, l31, l30) of lines through the last one, so either can be cancelled out
+    // unless three control points are collinear
+    let cancel_last = || {
+        // a t² + b t(1 - t) = 0
+        solve_pencil(l30, l20, l10).map(|(c1, c2)| {
+            let a = eval(l32 * c1 + l31 * c2 + l30);
+            let b = eval(l31 * c1 + (l30 + l21) * c2 + l20);
+            (b, b - a)
+        })
+    };
+    let cancel_first = || {
+        // b t(1 - t) + c (1 - t)² = 0
+        solve_pencil(l31, l30, l32).map(|(c1, c2)| {
+            let b = eval(l31 + (l30 + l21) * c1 + l20 * c2);
+            let c = eval(l30 + l20 * c1 + l10 * c2);
+            (c, c - b)
+        })
+    };
+
+    // both linear forms vanish at the double point of the curve
+    let (numerator, denominator) = cancel_last().or_else(cancel_first)?;
+    let t = numerator / denominator;
+    if t.is_finite() {
+        Some(t)
+    } else {
+        None
+    }
 }
 
 #[test]
@@ -113,3 +310,133 @@ fn test_implicit_cubic() {
         );
     }
 }
+
+#[test]
+fn test_implicit_line_quadratic() {
+    use super::evaluate;
+    use cgmath::{assert_relative_eq, assert_relative_ne};
+
+    let line = [Point2::new(1_f64, 0.), Point2::new(5., 2.)];
+    let quadratic = [
+        Point2::new(1_f64, 0.),
+        Point2::new(5., 0.),
+        Point2::new(4., 3.),
+    ];
+    let i_line = implicit_line(line);
+    let i_quadratic = implicit_quadratic(quadratic);
+
+    for i in 0..10 {
+        let t = (i as f64) / 10.;
+
+        let p = evaluate(&line, t);
+        assert_relative_eq!(i_line.eval(p.x, p.y), 0., epsilon = 1e-8);
+        assert_relative_ne!(i_line.eval(p.x, p.y + 1.), 0., epsilon = 1e-8);
+
+        let p = evaluate(&quadratic, t);
+        assert_relative_eq!(i_quadratic.eval(p.x, p.y), 0., epsilon = 1e-8);
+        assert_relative_ne!(i_quadratic.eval(p.x, p.y + 1.), 0., epsilon = 1e-8);
+        assert_relative_ne!(i_quadratic.eval(p.x + 0.1, p.y), 0., epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn test_implicit_curve() {
+    use super::evaluate;
+    use cgmath::assert_relative_eq;
+
+    let cubic = [
+        Point2::new(1_f64, 0.),
+        Point2::new(5., 0.),
+        Point2::new(5., 2.),
+        Point2::new(4., 3.),
+    ];
+    let i_cubic = implicit_cubic(cubic);
+    let i_general = implicit_curve(&cubic);
+    assert_eq!(i_general.degree(), 3);
+    for &(x, y) in [(0., 0.), (1., 2.), (-3., 0.5)].iter() {
+        assert_relative_eq!(
+            i_general.eval(&[x, y]),
+            i_cubic.eval(x, y),
+            max_relative = 1e-10
+        );
+    }
+
+    let quintic = vec![
+        Point2::new(0_f64, 0.),
+        Point2::new(1., 2.),
+        Point2::new(3., -1.),
+        Point2::new(4., 3.),
+        Point2::new(6., 1.),
+        Point2::new(7., 2.),
+    ];
+    let i_quintic = implicit_curve(&quintic);
+    assert_eq!(i_quintic.degree(), 5);
+    for i in 0..10 {
+        let t = (i as f64) / 10.;
+        let p = evaluate(&quintic, t);
+        let on = i_quintic.eval(&[p.x, p.y]);
+        let off = i_quintic.eval(&[p.x, p.y + 1.]);
+        // coefficients are large, so compare to a point off the curve
+        assert!(on.abs() < off.abs() * 1e-12);
+    }
+}
+
+#[test]
+fn test_invert_cubic() {
+    use super::evaluate;
+    use cgmath::assert_abs_diff_eq;
+
+    let curves = [
+        [
+            Point2::new(0_f64, 0.),
+            Point2::new(1., 3.),
+            Point2::new(4., 1.),
+            Point2::new(5., 5.),
+        ],
+        // first, third and last control points are collinear
+        [
+            Point2::new(0., 0.),
+            Point2::new(1., 3.),
+            Point2::new(2., 1.),
+            Point2::new(4., 2.),
+        ],
+        // self-intersecting
+        [
+            Point2::new(0., 0.),
+            Point2::new(4., 3.),
+            Point2::new(-1., 3.),
+            Point2::new(3., 0.),
+        ],
+    ];
+
+    for curve in curves.iter() {
+        for i in 0..=10 {
+            let t = (i as f64) / 10.;
+            let inverted = invert_cubic(*curve, evaluate(curve, t)).unwrap();
+            assert_abs_diff_eq!(inverted, t, epsilon = 1e-8);
+        }
+    }
+
+    // the double point of this curve is at t = 0 and t = 2
+    let curve = [
+        Point2::new(1., 0.),
+        Point2::new(5., 0.),
+        Point2::new(5., 2.),
+        Point2::new(4., 3.),
+    ];
+    assert_eq!(invert_cubic(curve, curve[0]), None);
+    assert_abs_diff_eq!(
+        invert_cubic(curve, evaluate(&curve, 0.5)).unwrap(),
+        0.5,
+        epsilon = 1e-8
+    );
+
+    // straight lines have no unique implicit form
+    let line = [
+        Point2::new(0., 0.),
+        Point2::new(1., 1.),
+        Point2::new(2., 2.),
+        Point2::new(3., 3.),
+    ];
+    assert_eq!(invert_cubic(line, Point2::new(1.5, 1.5)), None);
+}
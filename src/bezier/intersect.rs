@@ -1,12 +1,226 @@
-use super::{evaluate, implicit_cubic, parametric_cubic};
-use cgmath::{BaseFloat, Point2};
+use super::clip::try_clip_outside;
+use super::derive::try_derive;
+use super::implicit::{try_implicit_cubic, try_implicit_curve, try_implicit_quadratic};
+use super::param::{try_parametric, try_parametric_cubic};
+use super::project::try_project_point;
+use super::subdiv::subdivide_range;
+use super::{evaluate, implicit_line, invert_cubic, BezierCurve};
+use crate::polynomial::{BernsteinPoly, MultiPolynomial, Poly1x2d, Poly2x2d, Poly3x2d, Polynomial};
+use crate::CastError;
+use cgmath::{BaseFloat, InnerSpace, Point2, Vector2};
+use std::cmp::Ordering;
 
-/// Finds intersections of two 2D cubic bézier curves.
+/// An intersection of two curves a and b.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Intersection<S> {
+    /// Parameter of the intersection on curve a.
+    pub t_a: S,
+    /// Parameter of the intersection on curve b.
+    pub t_b: S,
+    /// The intersection point.
+    pub point: Point2<S>,
+    /// Whether the curves touch tangentially at this point instead of crossing transversally.
+    pub tangential: bool,
+}
+
+/// An overlap of two curves a and b, i.e. a segment both curves have in common.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Overlap<S> {
+    /// Parameter range of the overlap on curve a, in ascending order.
+    pub t_a: [S; 2],
+    /// Parameter range of the overlap on curve b, corresponding to `t_a`. It is in descending
+    /// order if the curves run in opposite directions.
+    pub t_b: [S; 2],
+}
+
+/// Intersections and overlaps of two curves a and b.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveIntersections<S> {
+    /// Intersections outside of the overlaps, sorted by their parameter on curve a.
+    pub intersections: Vec<Intersection<S>>,
+    /// Segments the curves have in common.
+    pub overlaps: Vec<Overlap<S>>,
+}
+
+impl<S> Default for CurveIntersections<S> {
+    fn default() -> Self {
+        CurveIntersections {
+            intersections: Vec::new(),
+            overlaps: Vec::new(),
+        }
+    }
+}
+
+impl<S> Overlap<S>
+where
+    S: BaseFloat,
+{
+    /// Returns whether the parameters lie within the overlap, up to `tolerance`.
+    pub(crate) fn contains(&self, t_a: S, t_b: S, tolerance: S) -> bool {
+        let (b0, b1) = (self.t_b[0].min(self.t_b[1]), self.t_b[0].max(self.t_b[1]));
+        t_a >= self.t_a[0] - tolerance
+            && t_a <= self.t_a[1] + tolerance
+            && t_b >= b0 - tolerance
+            && t_b <= b1 + tolerance
+    }
+
+    /// Returns pairs of parameter ranges on a and b that cover both curves except for the
+    /// overlap.
+    pub(crate) fn outside_ranges(&self) -> Vec<((S, S), (S, S))> {
+        let unit = (S::zero(), S::one());
+        let a_inside = (self.t_a[0], self.t_a[1]);
+        let (b0, b1) = (self.t_b[0].min(self.t_b[1]), self.t_b[0].max(self.t_b[1]));
+        let mut ranges = Vec::new();
+        for &a_range in [(S::zero(), self.t_a[0]), (self.t_a[1], S::one())].iter() {
+            if a_range.0 < a_range.1 {
+                ranges.push((a_range, unit));
+            }
+        }
+        for &b_range in [(S::zero(), b0), (b1, S::one())].iter() {
+            if b_range.0 < b_range.1 {
+                ranges.push((a_inside, b_range));
+            }
+        }
+        ranges
+    }
+}
+
+/// Finds the segment two 2D bézier curves have in common, if any.
+///
+/// Curves of different degrees are compared after elevating the one of lower degree. Returns None
+/// if the number of points cannot be represented by the scalar type S.
 ///
-/// Curve intersections are given as a t parameter for the curve given in `a` and a 2D point.
+/// # Details
+/// The curve of lower degree is implicitized, and the curves can only overlap if substituting
+/// the other curve in parametric form yields a polynomial that vanishes up to rounding error.
+/// Both ends of an overlap are endpoints of a or b, so each endpoint is then projected onto the
+/// other curve (see [`project_point`](super::project_point)). For each pair of endpoints lying
+/// on the other curve, the two curves are cut to the range between them, and they overlap if
+/// their control points coincide.
+pub fn overlap<S, L1, L2>(a: &L1, b: &L2) -> Option<Overlap<S>>
+where
+    L1: BezierCurve<Point2<S>>,
+    L2: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let a: Vec<Point2<S>> = (0..a.count()).map(|i| *a.get(i)).collect();
+    let b: Vec<Point2<S>> = (0..b.count()).map(|i| *b.get(i)).collect();
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let polynomial = if b.len() <= a.len() {
+        ImplicitForm::new(&b).ok()?.subst_curve(&a).ok()?
+    } else {
+        ImplicitForm::new(&a).ok()?.subst_curve(&b).ok()?
+    };
+    if polynomial.is_some() {
+        return None;
+    }
+    try_find_overlap(&a, &b).ok()?
+}
+
+/// Elevates a curve to `len` control points.
+fn try_elevate<S>(curve: Vec<Point2<S>>, len: usize) -> Result<Vec<Point2<S>>, CastError>
+where
+    S: BaseFloat,
+{
+    if curve.len() >= len {
+        return Ok(curve);
+    }
+    let by = len - curve.len();
+    let x = BernsteinPoly::from(curve.iter().map(|p| p.x).collect::<Vec<_>>()).try_elevate(by)?;
+    let y = BernsteinPoly::from(curve.iter().map(|p| p.y).collect::<Vec<_>>()).try_elevate(by)?;
+    Ok(x.coeffs
+        .into_iter()
+        .zip(y.coeffs)
+        .map(|(x, y)| Point2::new(x, y))
+        .collect())
+}
+
+/// Finds the segment two curves with at least two points have in common; see [`overlap`].
+///
+/// Unlike [`overlap`], this does not check whether the curves lie on the same algebraic curve.
+fn try_find_overlap<S>(a: &[Point2<S>], b: &[Point2<S>]) -> Result<Option<Overlap<S>>, CastError>
+where
+    S: BaseFloat,
+{
+    let a = a.to_vec();
+    let b = b.to_vec();
+    let sqrt_epsilon = S::default_epsilon().sqrt();
+    let scale = a
+        .iter()
+        .chain(b.iter())
+        .fold(S::one(), |m, p| m.max(p.x.abs()).max(p.y.abs()));
+    let tolerance = scale * sqrt_epsilon;
+
+    // pairs of parameters on a and b
+    let mut candidates = Vec::new();
+    for &t in [S::zero(), S::one()].iter() {
+        let projection = try_project_point(&b, evaluate(&a, t))?;
+        if projection.distance <= tolerance {
+            candidates.push((t, projection.t));
+        }
+        let projection = try_project_point(&a, evaluate(&b, t))?;
+        if projection.distance <= tolerance {
+            candidates.push((projection.t, t));
+        }
+    }
+
+    let len = a.len().max(b.len());
+    let mut longest: Option<Overlap<S>> = None;
+    for (i, &p) in candidates.iter().enumerate() {
+        for &q in &candidates[i + 1..] {
+            let ((t_a0, t_b0), (t_a1, t_b1)) = if p.0 <= q.0 { (p, q) } else { (q, p) };
+            if t_a1 - t_a0 <= sqrt_epsilon || (t_b1 - t_b0).abs() <= sqrt_epsilon {
+                continue;
+            }
+            if longest.is_some_and(|o| o.t_a[1] - o.t_a[0] >= t_a1 - t_a0) {
+                continue;
+            }
+            let a_segment = try_elevate(subdivide_range(&a, t_a0, t_a1), len)?;
+            let b_segment = subdivide_range(&b, t_b0.min(t_b1), t_b0.max(t_b1));
+            let mut b_segment = try_elevate(b_segment, len)?;
+            if t_b0 > t_b1 {
+                b_segment.reverse();
+            }
+            let coincident = a_segment
+                .iter()
+                .zip(b_segment.iter())
+                .all(|(p, q)| (p - q).magnitude() <= tolerance);
+            if coincident {
+                longest = Some(Overlap {
+                    t_a: [t_a0, t_a1],
+                    t_b: [t_b0, t_b1],
+                });
+            }
+        }
+    }
+    Ok(longest)
+}
+
+/// Finds intersections and overlaps of two curves that lie on the same algebraic curve, using
+/// bézier clipping since the implicit form cannot separate them.
+fn try_intersect_coincident<S>(
+    a: &[Point2<S>],
+    b: &[Point2<S>],
+) -> Result<CurveIntersections<S>, CastError>
+where
+    S: BaseFloat,
+{
+    let overlap = try_find_overlap(a, b)?;
+    let tolerance = S::default_epsilon().sqrt();
+    let intersections = try_clip_outside(a, b, overlap.as_ref(), tolerance)?;
+    Ok(CurveIntersections {
+        intersections,
+        overlaps: overlap.into_iter().collect(),
+    })
+}
+
+/// Finds intersections of two 2D cubic bézier curves.
 ///
-/// # Panics
-/// - if S is not isomorphic to f64
+/// Intersections are sorted by their parameter on curve a. If the curves overlap along a segment
+/// (see [`overlap`]), the overlap is returned along with the intersections outside of it.
+/// Returns an error if constants used in the computation cannot be represented by S.
 ///
 /// # Details
 /// Intersection is implemented using the implicitization method outlined in chapter 17 of
@@ -14,32 +228,300 @@ use cgmath::{BaseFloat, Point2};
 /// "Implicitization, Inversion, and Intersection of Planar Rational Cubic Curves" by Sederberg,
 /// Anderson, and Goldman in *Computer Vision, Graphics, and Image Processing* (1985).
 ///
-/// This method creates an implicit function for the second curve (see [`implicit_cubic`]),
-/// substitutes the first curve into the implicit function in parametric form (see
-/// [`parametric_cubic`]), and solves for the equation's roots (see [`roots::find_roots_eigen`]).
+/// This method creates an implicit function for the second curve (see
+/// [`implicit_cubic`](super::implicit_cubic)), substitutes the first curve into the implicit
+/// function in parametric form (see [`parametric_cubic`](super::parametric_cubic)), and solves for
+/// the equation's roots in [0, 1] natively in S.
+/// Parameters on the second curve are then recovered using [`invert_cubic`], falling back to
+/// [`project_point`](super::project_point) at its double point. If the equation vanishes, both
+/// curves lie on the same algebraic curve, and they are intersected using bézier clipping
+/// instead (see [`intersect_clip`](super::intersect_clip)).
+///
+/// No computation converts to another scalar type, so S may be any type implementing
+/// [`BaseFloat`] (e.g. f32, f64, or a dual number or interval type implementing the `num_traits`
+/// and `approx` traits it requires). Types that cannot implement these traits, such as intervals
+/// without a total order, are not supported.
 pub fn intersect_cubic<S>(
     a: [Point2<S>; 4],
     b: [Point2<S>; 4],
-) -> impl Iterator<Item = (f64, Point2<S>)>
+) -> Result<CurveIntersections<S>, CastError>
 where
-    S: BaseFloat + 'static,
+    S: BaseFloat,
 {
-    let b_implicit = implicit_cubic(b);
-    let (a_x, a_y) = parametric_cubic(a);
+    let polynomial = match ImplicitForm::Cubic(try_implicit_cubic(b)?).subst_curve(&a)? {
+        Some(polynomial) => polynomial,
+        None => return try_intersect_coincident(&a, &b),
+    };
 
-    let polynomial = b_implicit
-        .subst(a_x.as_slice(), a_y.as_slice())
-        .into_iter()
-        .map(|s| s.to_f64().unwrap())
-        .collect();
+    let a_derivative: [Vector2<S>; 3] = try_derive(&a)?;
+    let b_derivative: [Vector2<S>; 3] = try_derive(&b)?;
+    // parameters may be slightly out of range due to numerical error
+    let tolerance = S::default_epsilon().sqrt();
 
-    roots::find_roots_eigen(polynomial)
-        .into_iter()
-        .filter(|t| *t >= 0. && *t <= 1.)
-        .map(move |t| (
-            t,
-            evaluate(&a, S::from(t).unwrap()),
-        ))
+    let mut intersections = Vec::new();
+    for t_a in polynomial.real_roots_in(S::zero(), S::one()) {
+        let point = evaluate(&a, t_a);
+        let t_b = match invert_cubic(b, point) {
+            Some(t_b) => t_b,
+            None => try_project_point(&b, point)?.t,
+        };
+        if t_b < -tolerance || t_b > S::one() + tolerance {
+            // intersection with the algebraic extension of b
+            continue;
+        }
+        let t_b = t_b.max(S::zero()).min(S::one());
+
+        let da = evaluate(&a_derivative, t_a);
+        let db = evaluate(&b_derivative, t_b);
+        let tangential = da.perp_dot(db).abs() <= da.magnitude() * db.magnitude() * tolerance;
+
+        intersections.push(Intersection {
+            t_a,
+            t_b,
+            point,
+            tangential,
+        });
+    }
+    Ok(CurveIntersections {
+        intersections,
+        overlaps: Vec::new(),
+    })
+}
+
+/// Implicit form of a curve, using fixed-size polynomials for low degrees.
+enum ImplicitForm<S> {
+    Line(Poly1x2d<S>),
+    Quadratic(Poly2x2d<S>),
+    Cubic(Poly3x2d<S>),
+    General(MultiPolynomial<S>),
+}
+
+impl<S> ImplicitForm<S>
+where
+    S: BaseFloat,
+{
+    /// Implicitizes a curve of degree ≥ 1.
+    fn new(curve: &[Point2<S>]) -> Result<Self, CastError> {
+        Ok(match *curve {
+            [a, b] => ImplicitForm::Line(implicit_line([a, b])),
+            [a, b, c] => ImplicitForm::Quadratic(try_implicit_quadratic([a, b, c])?),
+            [a, b, c, d] => ImplicitForm::Cubic(try_implicit_cubic([a, b, c, d])?),
+            _ => ImplicitForm::General(try_implicit_curve(curve)?),
+        })
+    }
+
+    fn subst(&self, x: &Polynomial<S>, y: &Polynomial<S>) -> Polynomial<S> {
+        let (xs, ys) = (&x.coeffs, &y.coeffs);
+        match self {
+            ImplicitForm::Line(f) => Polynomial::from(f.subst(xs, ys)),
+            ImplicitForm::Quadratic(f) => Polynomial::from(f.subst(xs, ys)),
+            ImplicitForm::Cubic(f) => Polynomial::from(f.subst(xs, ys)),
+            ImplicitForm::General(f) => f.subst(&[x.clone(), y.clone()]),
+        }
+    }
+
+    /// Returns an upper bound of the absolute value of the implicit function on the square
+    /// [-r, r]², i.e. the sum of its absolute coefficients weighted by r to the term's degree.
+    fn magnitude(&self, r: S) -> S {
+        let f = match self {
+            ImplicitForm::Line(f) => MultiPolynomial::from(*f),
+            ImplicitForm::Quadratic(f) => MultiPolynomial::from(*f),
+            ImplicitForm::Cubic(f) => MultiPolynomial::from(*f),
+            ImplicitForm::General(f) => f.clone(),
+        };
+        f.terms().fold(S::zero(), |sum, (exponents, c)| {
+            sum + exponents
+                .iter()
+                .fold(c.abs(), |term, &e| term * r.powi(e as i32))
+        })
+    }
+
+    /// Substitutes a curve in parametric form into the implicit function.
+    ///
+    /// Returns None if the resulting polynomial vanishes up to rounding error, i.e. if the curve
+    /// lies on the same algebraic curve. A vanishing implicit function, e.g. of a single point,
+    /// does not contain any curve.
+    fn subst_curve(&self, curve: &[Point2<S>]) -> Result<Option<Polynomial<S>>, CastError> {
+        let xy = try_parametric(&curve.to_vec())?;
+        let (x, y) = (&xy[0], &xy[1]);
+        let polynomial = self.subst(x, y);
+
+        // the parametric form is bounded by the sum of its absolute coefficients on [0, 1]
+        let bound = |coeffs: &[S]| coeffs.iter().fold(S::zero(), |sum, c| sum + c.abs());
+        let r = bound(&x.coeffs).max(bound(&y.coeffs)).max(S::one());
+        let tolerance = self.magnitude(r) * S::default_epsilon().sqrt();
+        if tolerance > S::zero() && bound(&polynomial.coeffs) <= tolerance {
+            Ok(None)
+        } else {
+            Ok(Some(polynomial))
+        }
+    }
+}
+
+/// Intersects a curve in parametric form with a curve in implicit form, returning parameters in
+/// that order.
+fn intersect_implicit<S>(
+    parametric: &[Point2<S>],
+    implicit: &[Point2<S>],
+) -> Result<CurveIntersections<S>, CastError>
+where
+    S: BaseFloat,
+{
+    let polynomial = match ImplicitForm::new(implicit)?.subst_curve(parametric)? {
+        Some(polynomial) => polynomial,
+        None => return try_intersect_coincident(parametric, implicit),
+    };
+
+    let parametric = parametric.to_vec();
+    let implicit = implicit.to_vec();
+    let parametric_derivative: Vec<Vector2<S>> = try_derive(&parametric)?;
+    let implicit_derivative: Vec<Vector2<S>> = try_derive(&implicit)?;
+    // parameters may be slightly out of range due to numerical error
+    let tolerance = S::default_epsilon().sqrt();
+    let scale = implicit
+        .iter()
+        .fold(S::one(), |m, p| m.max(p.x.abs()).max(p.y.abs()));
+
+    let mut intersections = Vec::new();
+    for t_a in polynomial.real_roots_in(S::zero(), S::one()) {
+        let point = evaluate(&parametric, t_a);
+        let t_b = match *implicit {
+            [a, b, c, d] => invert_cubic([a, b, c, d], point),
+            _ => None,
+        };
+        let t_b = match t_b {
+            Some(t_b) => t_b,
+            None => try_project_point(&implicit, point)?.t,
+        };
+        if t_b < -tolerance || t_b > S::one() + tolerance {
+            continue;
+        }
+        let t_b = t_b.max(S::zero()).min(S::one());
+        // projection clamps points on the algebraic extension of the implicit curve
+        if (evaluate(&implicit, t_b) - point).magnitude() > scale * tolerance {
+            continue;
+        }
+
+        let da = evaluate(&parametric_derivative, t_a);
+        let db = evaluate(&implicit_derivative, t_b);
+        let tangential = da.perp_dot(db).abs() <= da.magnitude() * db.magnitude() * tolerance;
+
+        intersections.push(Intersection {
+            t_a,
+            t_b,
+            point,
+            tangential,
+        });
+    }
+    Ok(CurveIntersections {
+        intersections,
+        overlaps: Vec::new(),
+    })
+}
+
+/// The self-intersection of a curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelfIntersection<S> {
+    /// The smaller parameter of the self-intersection.
+    pub t1: S,
+    /// The larger parameter of the self-intersection.
+    pub t2: S,
+    /// The self-intersection point.
+    pub point: Point2<S>,
+}
+
+/// Finds the self-intersection (the double point of a loop) of a 2D cubic bézier curve.
+///
+/// Returns None if the curve does not intersect itself within [0, 1], if it has a cusp instead,
+/// or if constants used in the computation cannot be represented by S.
+///
+/// # Details
+/// With the curve in power form B(t) = a t³ + b t² + c t + d, distinct parameters s and t with
+/// B(s) = B(t) satisfy (B(s) - B(t)) / (s - t) = a (s² + st + t²) + b (s + t) + c = 0.
+/// Substituting u = s + t and v = st yields a (u² - v) + b u + c = 0; taking the cross product with
+/// a eliminates v, and s and t are the roots of z² - u z + v.
+pub fn self_intersect_cubic<S>(curve: [Point2<S>; 4]) -> Option<SelfIntersection<S>>
+where
+    S: BaseFloat,
+{
+    let (x, y) = try_parametric_cubic(curve).ok()?;
+    let a = Vector2::new(x.xxx, y.xxx);
+    let b = Vector2::new(x.xx, y.xx);
+    let c = Vector2::new(x.x, y.x);
+
+    let ab = a.perp_dot(b);
+    if ab == S::zero() {
+        // a is zero (no loop in quadratics) or parallel to b
+        return None;
+    }
+    let u = -a.perp_dot(c) / ab;
+    let v = u * u + (a.dot(b) * u + a.dot(c)) / a.magnitude2();
+
+    let four = S::from(4)?;
+    let two = S::from(2)?;
+    let discriminant = u * u - four * v;
+    if discriminant <= S::zero() {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let (t1, t2) = ((u - root) / two, (u + root) / two);
+    if t1 < S::zero() || t2 > S::one() {
+        return None;
+    }
+    Some(SelfIntersection {
+        t1,
+        t2,
+        point: evaluate(&curve, t1),
+    })
+}
+
+/// Finds intersections of two 2D bézier curves of any degree.
+///
+/// Intersections are sorted by their parameter on curve a. If the curves overlap along a segment
+/// (see [`overlap`]), the overlap is returned along with the intersections outside of it. Curves
+/// of every degree ≥ 1 are supported; curves with fewer than two points have no intersections.
+/// Returns an error if constants used in the computation cannot be represented by S.
+///
+/// # Details
+/// The curve of lower degree is implicitized (see [`implicit_curve`](super::implicit_curve)), and
+/// the other curve is substituted into the implicit function in parametric form. See
+/// [`intersect_cubic`] for details.
+pub fn intersect<S, L1, L2>(a: &L1, b: &L2) -> Result<CurveIntersections<S>, CastError>
+where
+    L1: BezierCurve<Point2<S>>,
+    L2: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let a: Vec<Point2<S>> = (0..a.count()).map(|i| *a.get(i)).collect();
+    let b: Vec<Point2<S>> = (0..b.count()).map(|i| *b.get(i)).collect();
+    if a.len() < 2 || b.len() < 2 {
+        return Ok(CurveIntersections::default());
+    }
+
+    if b.len() <= a.len() {
+        intersect_implicit(&a, &b)
+    } else {
+        let mut result = intersect_implicit(&b, &a)?;
+        for intersection in &mut result.intersections {
+            std::mem::swap(&mut intersection.t_a, &mut intersection.t_b);
+        }
+        result
+            .intersections
+            .sort_by(|p, q| p.t_a.partial_cmp(&q.t_a).unwrap_or(Ordering::Equal));
+        for overlap in &mut result.overlaps {
+            let (t_a, t_b) = (overlap.t_b, overlap.t_a);
+            // keep the range on a in ascending order
+            *overlap = if t_a[0] <= t_a[1] {
+                Overlap { t_a, t_b }
+            } else {
+                Overlap {
+                    t_a: [t_a[1], t_a[0]],
+                    t_b: [t_b[1], t_b[0]],
+                }
+            };
+        }
+        Ok(result)
+    }
 }
 
 #[test]
@@ -58,13 +540,13 @@ fn test_intersect_cubic() {
         Point2::new(14., 10.),
         Point2::new(11., 1.),
     ];
-    let mut ips = intersect_cubic(curve1, curve2).collect::<Vec<_>>();
-    let mut ips2 = intersect_cubic(curve2, curve1).collect::<Vec<_>>();
+    let mut ips = intersect_cubic(curve1, curve2).unwrap().intersections;
+    let mut ips2 = intersect_cubic(curve2, curve1).unwrap().intersections;
 
-    ips.sort_by(|a, b| a.1.x.partial_cmp(&b.1.x).unwrap());
-    ips2.sort_by(|a, b| a.1.x.partial_cmp(&b.1.x).unwrap());
+    ips.sort_by(|a, b| a.point.x.partial_cmp(&b.point.x).unwrap());
+    ips2.sort_by(|a, b| a.point.x.partial_cmp(&b.point.x).unwrap());
 
-    let p_ref = vec![
+    let p_ref = [
         Point2::new(2.43, 4.11),
         Point2::new(7.12, 4.54),
         Point2::new(11.26, 1.88),
@@ -74,7 +556,294 @@ fn test_intersect_cubic() {
     assert_eq!(ips.len(), ips2.len());
 
     for (i, (j, k)) in p_ref.iter().zip(ips.iter().zip(ips2.iter())) {
-        assert_relative_eq!(*i, j.1, epsilon = 0.02);
-        assert_relative_eq!(j.1, k.1, epsilon = 1e-5);
+        assert_relative_eq!(*i, j.point, epsilon = 0.02);
+        assert_relative_eq!(j.point, k.point, epsilon = 1e-5);
+        assert_relative_eq!(evaluate(&curve2, j.t_b), j.point, epsilon = 1e-5);
+        assert_relative_eq!(j.t_a, k.t_b, epsilon = 1e-5);
+        assert_relative_eq!(j.t_b, k.t_a, epsilon = 1e-5);
+        assert!(!j.tangential);
+    }
+}
+
+#[test]
+fn test_intersect_cubic_tangential() {
+    use cgmath::assert_relative_eq;
+
+    // two arches touching at their apex (2, 1.5)
+    let curve1 = [
+        Point2::new(0_f32, 0.),
+        Point2::new(1., 2.),
+        Point2::new(3., 2.),
+        Point2::new(4., 0.),
+    ];
+    let curve2 = [
+        Point2::new(0., 3.),
+        Point2::new(1., 1.),
+        Point2::new(3., 1.),
+        Point2::new(4., 3.),
+    ];
+    let ips = intersect_cubic(curve1, curve2).unwrap().intersections;
+    assert_eq!(ips.len(), 1);
+    assert_relative_eq!(ips[0].point, Point2::new(2., 1.5), epsilon = 1e-3);
+    assert_relative_eq!(ips[0].t_a, 0.5, epsilon = 1e-3);
+    assert_relative_eq!(ips[0].t_b, 0.5, epsilon = 1e-3);
+    assert!(ips[0].tangential);
+}
+
+#[test]
+fn test_intersect() {
+    use cgmath::assert_relative_eq;
+
+    // (2t, 4t - 4t²) intersects y = 0.5 at t = (1 ± √0.5) / 2
+    let quadratic = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(2., 0.),
+    ];
+    let line = [Point2::new(-1., 0.5), Point2::new(3., 0.5)];
+    let ips = intersect(&quadratic, &line).unwrap().intersections;
+    assert_eq!(ips.len(), 2);
+    for (ip, t) in ips
+        .iter()
+        .zip([0.5 - 0.5_f64.sqrt() / 2., 0.5 + 0.5_f64.sqrt() / 2.].iter())
+    {
+        assert_relative_eq!(ip.t_a, *t, epsilon = 1e-8);
+        assert_relative_eq!(ip.t_b, (2. * t + 1.) / 4., epsilon = 1e-8);
+        assert_relative_eq!(ip.point, Point2::new(2. * t, 0.5), epsilon = 1e-8);
+        assert!(!ip.tangential);
+    }
+
+    let cubic = [
+        Point2::new(0., 0.),
+        Point2::new(5., 11.),
+        Point2::new(7., 2.),
+        Point2::new(16., 0.),
+    ];
+    let quartic = vec![
+        Point2::new(-1., 3.),
+        Point2::new(4., -2.),
+        Point2::new(8., 9.),
+        Point2::new(12., -3.),
+        Point2::new(17., 4.),
+    ];
+    let curves: [&[Point2<f64>]; 4] = [&line, &quadratic, &cubic, &quartic];
+
+    for a in curves.iter() {
+        for b in curves.iter() {
+            let a = a.to_vec();
+            let b = b.to_vec();
+            let ips = intersect(&a, &b).unwrap().intersections;
+            let ips2 = intersect(&b, &a).unwrap().intersections;
+            assert_eq!(ips.len(), ips2.len());
+            for ip in &ips {
+                assert_relative_eq!(evaluate(&a, ip.t_a), ip.point, epsilon = 1e-6);
+                assert_relative_eq!(evaluate(&b, ip.t_b), ip.point, epsilon = 1e-6);
+                assert!(ips2
+                    .iter()
+                    .any(|ip2| (ip2.t_a - ip.t_b).abs() < 1e-6 && (ip2.t_b - ip.t_a).abs() < 1e-6));
+            }
+        }
+    }
+
+    // agrees with intersect_cubic
+    let cubic2 = [
+        Point2::new(1., 6.),
+        Point2::new(2., 0.),
+        Point2::new(14., 10.),
+        Point2::new(11., 1.),
+    ];
+    let ips = intersect(&cubic, &cubic2).unwrap().intersections;
+    let ips2 = intersect_cubic(cubic, cubic2).unwrap().intersections;
+    assert_eq!(ips.len(), 3);
+    for (ip, ip2) in ips.iter().zip(ips2.iter()) {
+        assert_relative_eq!(ip.t_a, ip2.t_a, epsilon = 1e-8);
+        assert_relative_eq!(ip.t_b, ip2.t_b, epsilon = 1e-8);
+    }
+
+    // curves of higher degree on both sides, and degenerate curves
+    let quintic = vec![
+        Point2::new(0., 0.),
+        Point2::new(3., 8.),
+        Point2::new(6., -6.),
+        Point2::new(9., 8.),
+        Point2::new(12., -6.),
+        Point2::new(15., 2.),
+    ];
+    let ips = intersect(&quartic, &quintic).unwrap().intersections;
+    assert!(!ips.is_empty());
+    for ip in &ips {
+        assert_relative_eq!(evaluate(&quartic, ip.t_a), ip.point, epsilon = 1e-6);
+        assert_relative_eq!(evaluate(&quintic, ip.t_b), ip.point, epsilon = 1e-6);
+    }
+    let point = vec![Point2::new(1., 1.); 5];
+    assert!(intersect(&point, &quintic)
+        .unwrap()
+        .intersections
+        .is_empty());
+    assert!(intersect(&quintic, &point)
+        .unwrap()
+        .intersections
+        .is_empty());
+    assert!(intersect(&vec![Point2::new(1., 1.)], &quintic)
+        .unwrap()
+        .intersections
+        .is_empty());
+}
+
+#[test]
+fn test_overlap() {
+    use super::subdiv::subdivide_range;
+    use cgmath::assert_abs_diff_eq;
+
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(5., 11.),
+        Point2::new(7., 2.),
+        Point2::new(16., 0.),
+    ];
+    let a = subdivide_range(&curve, 0.2, 0.7);
+    let mut b = subdivide_range(&curve, 0.5, 0.9);
+    b.reverse();
+    let a = [a[0], a[1], a[2], a[3]];
+    let b = [b[0], b[1], b[2], b[3]];
+
+    let o = overlap(&a, &b).unwrap();
+    assert_abs_diff_eq!(o.t_a[0], 0.6, epsilon = 1e-8);
+    assert_abs_diff_eq!(o.t_a[1], 1., epsilon = 1e-8);
+    assert_abs_diff_eq!(o.t_b[0], 1., epsilon = 1e-8);
+    assert_abs_diff_eq!(o.t_b[1], 0.5, epsilon = 1e-8);
+    let o = overlap(&b, &a).unwrap();
+    assert_abs_diff_eq!(o.t_a[0], 0.5, epsilon = 1e-8);
+    assert_abs_diff_eq!(o.t_b[0], 1., epsilon = 1e-8);
+
+    let o = overlap(&curve, &curve).unwrap();
+    assert_eq!(o.t_a, [0., 1.]);
+    assert_eq!(o.t_b, [0., 1.]);
+
+    // the overlap is returned instead of intersections
+    let result = intersect_cubic(a, b).unwrap();
+    assert!(result.intersections.is_empty());
+    assert_eq!(result.overlaps.len(), 1);
+    assert_abs_diff_eq!(result.overlaps[0].t_a[0], 0.6, epsilon = 1e-8);
+    assert_abs_diff_eq!(result.overlaps[0].t_b[1], 0.5, epsilon = 1e-8);
+    let result = intersect(&a, &b).unwrap();
+    assert!(result.intersections.is_empty());
+    assert_eq!(result.overlaps.len(), 1);
+    let result = super::intersect_clip(&a, &b, 1e-10);
+    assert!(result.intersections.is_empty());
+    assert_eq!(result.overlaps.len(), 1);
+
+    // curves of different degrees: a quadratic and a degree-elevated part of it
+    let quadratic = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(2., 0.),
+    ];
+    let part = subdivide_range(&quadratic, 0.3, 1.);
+    let elevated = vec![
+        part[0],
+        part[0] + (part[1] - part[0]) * (2. / 3.),
+        part[2] + (part[1] - part[2]) * (2. / 3.),
+        part[2],
+    ];
+    let o = overlap(&quadratic, &elevated).unwrap();
+    assert_abs_diff_eq!(o.t_a[0], 0.3, epsilon = 1e-8);
+    assert_abs_diff_eq!(o.t_a[1], 1., epsilon = 1e-8);
+    assert_abs_diff_eq!(o.t_b[0], 0., epsilon = 1e-8);
+    assert_abs_diff_eq!(o.t_b[1], 1., epsilon = 1e-8);
+    let result = intersect(&elevated, &quadratic).unwrap();
+    assert!(result.intersections.is_empty());
+    assert_abs_diff_eq!(result.overlaps[0].t_b[0], 0.3, epsilon = 1e-8);
+
+    // parts of a loop sharing [0.5, 0.6] that also cross at the double point
+    let looped = [
+        Point2::new(0., 0.),
+        Point2::new(4., 3.),
+        Point2::new(-1., 3.),
+        Point2::new(3., 0.),
+    ];
+    let double_point = super::self_intersect_cubic(looped).unwrap();
+    let e = subdivide_range(&looped, 0., 0.6);
+    let f = subdivide_range(&looped, 0.5, 1.);
+    let (e, f) = ([e[0], e[1], e[2], e[3]], [f[0], f[1], f[2], f[3]]);
+    let results = [
+        intersect_cubic(e, f).unwrap(),
+        intersect(&e, &f).unwrap(),
+        super::intersect_clip(&e, &f, 1e-10),
+    ];
+    for result in results.iter() {
+        assert_eq!(result.overlaps.len(), 1);
+        assert_abs_diff_eq!(result.overlaps[0].t_a[0], 5. / 6., epsilon = 1e-8);
+        assert_abs_diff_eq!(result.overlaps[0].t_b[1], 0.2, epsilon = 1e-8);
+        assert_eq!(result.intersections.len(), 1);
+        let ip = result.intersections[0];
+        assert_abs_diff_eq!(ip.t_a, double_point.t1 / 0.6, epsilon = 1e-6);
+        assert_abs_diff_eq!(ip.t_b, (double_point.t2 - 0.5) / 0.5, epsilon = 1e-6);
+        assert_abs_diff_eq!(ip.point, double_point.point, epsilon = 1e-6);
+        assert!(!ip.tangential);
     }
+
+    // touching at an endpoint only
+    let c = subdivide_range(&curve, 0.7, 1.);
+    assert_eq!(overlap(&a.to_vec(), &c), None);
+    let result = intersect(&a, &c).unwrap();
+    assert!(result.overlaps.is_empty());
+    assert_eq!(result.intersections.len(), 1);
+    assert_abs_diff_eq!(result.intersections[0].t_a, 1., epsilon = 1e-6);
+    assert_abs_diff_eq!(result.intersections[0].t_b, 0., epsilon = 1e-6);
+    assert!(result.intersections[0].tangential);
+
+    // crossing curves
+    let d = [
+        Point2::new(1., 6.),
+        Point2::new(2., 0.),
+        Point2::new(14., 10.),
+        Point2::new(11., 1.),
+    ];
+    assert_eq!(overlap(&curve, &d), None);
+}
+
+#[test]
+fn test_self_intersect_cubic() {
+    use cgmath::assert_abs_diff_eq;
+
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(4., 3.),
+        Point2::new(-1., 3.),
+        Point2::new(3., 0.),
+    ];
+    let si = self_intersect_cubic(curve).unwrap();
+    assert!(si.t1 < si.t2);
+    // the curve is symmetric
+    assert_abs_diff_eq!(si.t1 + si.t2, 1., epsilon = 1e-10);
+    assert_abs_diff_eq!(si.point.x, 1.5, epsilon = 1e-10);
+    assert_abs_diff_eq!(evaluate(&curve, si.t2), si.point, epsilon = 1e-10);
+
+    // the double point of this curve is at t = 0 and t = 2
+    let curve = [
+        Point2::new(1., 0.),
+        Point2::new(5., 0.),
+        Point2::new(5., 2.),
+        Point2::new(4., 3.),
+    ];
+    assert_eq!(self_intersect_cubic(curve), None);
+
+    // arch without a loop
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(3., 2.),
+        Point2::new(4., 0.),
+    ];
+    assert_eq!(self_intersect_cubic(curve), None);
+
+    // cusp
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(2., 1.),
+        Point2::new(0., 1.),
+        Point2::new(2., 0.),
+    ];
+    assert_eq!(self_intersect_cubic(curve), None);
 }
@@ -0,0 +1,159 @@
+use super::{evaluate, parametric, BezierCurve};
+use crate::polynomial::Polynomial;
+use cgmath::{BaseFloat, InnerSpace, Point2, Vector2};
+
+/// An intersection of a curve and a line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineIntersection<S> {
+    /// Parameter of the intersection on the curve.
+    pub t: S,
+    /// Parameter of the intersection on the line, such that the point is origin + s · direction.
+    pub s: S,
+    /// The intersection point.
+    pub point: Point2<S>,
+}
+
+/// Finds intersections of a 2D bézier curve and the line through `origin` along `direction`,
+/// keeping those whose line parameter satisfies `keep`.
+fn intersect_line_where<S, L, F>(
+    curve: &L,
+    origin: Point2<S>,
+    direction: Vector2<S>,
+    keep: F,
+) -> Vec<LineIntersection<S>>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+    F: Fn(S) -> bool,
+{
+    let n = curve.count();
+    let length2 = direction.magnitude2();
+    if n == 0 || length2 == S::zero() {
+        return Vec::new();
+    }
+
+    // n · (p - origin) = 0 with normal n = (-direction.y, direction.x)
+    let xy = parametric(curve);
+    let polynomial = xy[0].clone() * -direction.y
+        + xy[1].clone() * direction.x
+        + Polynomial::from(vec![direction.y * origin.x - direction.x * origin.y]);
+
+    polynomial
+        .real_roots_in(S::zero(), S::one())
+        .into_iter()
+        .filter_map(|t| {
+            let point = evaluate(curve, t);
+            let s = (point - origin).dot(direction) / length2;
+            if keep(s) {
+                Some(LineIntersection { t, s, point })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Finds intersections of a 2D bézier curve and the infinite line through `origin` along
+/// `direction`.
+///
+/// Intersections are sorted by their curve parameter. A zero direction yields no intersections,
+/// and neither do curves that lie on the line.
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+///
+/// # Details
+/// The curve is substituted in power form into the implicit equation of the line, which yields a
+/// polynomial of the same degree as the curve. Its roots in [0, 1] are found natively in S.
+pub fn intersect_line<S, L>(
+    curve: &L,
+    origin: Point2<S>,
+    direction: Vector2<S>,
+) -> Vec<LineIntersection<S>>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    intersect_line_where(curve, origin, direction, |_| true)
+}
+
+/// Finds intersections of a 2D bézier curve and the ray starting at `origin` along `direction`,
+/// i.e. with a line parameter s ≥ 0; see [`intersect_line`].
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+pub fn intersect_ray<S, L>(
+    curve: &L,
+    origin: Point2<S>,
+    direction: Vector2<S>,
+) -> Vec<LineIntersection<S>>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    intersect_line_where(curve, origin, direction, |s| s >= S::zero())
+}
+
+/// Finds intersections of a 2D bézier curve and the line segment from `start` to `end`, i.e.
+/// with a line parameter 0 ≤ s ≤ 1; see [`intersect_line`].
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+pub fn intersect_segment<S, L>(
+    curve: &L,
+    start: Point2<S>,
+    end: Point2<S>,
+) -> Vec<LineIntersection<S>>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    intersect_line_where(curve, start, end - start, |s| {
+        s >= S::zero() && s <= S::one()
+    })
+}
+
+#[test]
+fn test_intersect_line() {
+    use cgmath::assert_abs_diff_eq;
+
+    // (2t, 4t - 4t²) intersects y = 0.5 at t = (1 ± √0.5) / 2
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(2., 0.),
+    ];
+    let t = [0.5 - 0.5_f64.sqrt() / 2., 0.5 + 0.5_f64.sqrt() / 2.];
+
+    let ips = intersect_line(&curve, Point2::new(1., 0.5), Vector2::new(2., 0.));
+    assert_eq!(ips.len(), 2);
+    for (ip, t) in ips.iter().zip(t.iter()) {
+        assert_abs_diff_eq!(ip.t, *t, epsilon = 1e-10);
+        assert_abs_diff_eq!(ip.s, (2. * t - 1.) / 2., epsilon = 1e-10);
+        assert_abs_diff_eq!(ip.point, Point2::new(2. * t, 0.5), epsilon = 1e-10);
+    }
+
+    let ips = intersect_ray(&curve, Point2::new(1., 0.5), Vector2::new(2., 0.));
+    assert_eq!(ips.len(), 1);
+    assert_abs_diff_eq!(ips[0].t, t[1], epsilon = 1e-10);
+
+    let ips = intersect_segment(&curve, Point2::new(-1., 0.5), Point2::new(1., 0.5));
+    assert_eq!(ips.len(), 1);
+    assert_abs_diff_eq!(ips[0].t, t[0], epsilon = 1e-10);
+    assert_abs_diff_eq!(ips[0].point, Point2::new(2. * t[0], 0.5), epsilon = 1e-10);
+
+    // vertical line through a cubic, which crosses it once
+    let curve = vec![
+        Point2::new(0., 0.),
+        Point2::new(5., 11.),
+        Point2::new(7., 2.),
+        Point2::new(16., 0.),
+    ];
+    let ips = intersect_ray(&curve, Point2::new(8., 10.), Vector2::new(0., -1.));
+    assert_eq!(ips.len(), 1);
+    assert_abs_diff_eq!(ips[0].point.x, 8., epsilon = 1e-10);
+    assert_abs_diff_eq!(evaluate(&curve, ips[0].t), ips[0].point, epsilon = 1e-10);
+    assert_abs_diff_eq!(ips[0].s, 10. - ips[0].point.y, epsilon = 1e-10);
+
+    assert!(intersect_line(&curve, Point2::new(8., 10.), Vector2::new(0., 0.)).is_empty());
+}
@@ -1,19 +1,35 @@
 //! Bézier curves
 
 mod arclen;
+mod bbox;
+mod classify;
+mod clip;
 mod curve;
 mod derive;
 mod eval;
+mod frenet;
 mod implicit;
 mod intersect;
+mod line;
+mod monotonic;
 mod param;
+mod project;
 mod subdiv;
+mod winding;
 
 pub use arclen::*;
+pub use bbox::*;
+pub use classify::*;
+pub use clip::*;
 pub use curve::*;
 pub use derive::*;
 pub use eval::*;
+pub use frenet::*;
 pub use implicit::*;
 pub use intersect::*;
+pub use line::*;
+pub use monotonic::*;
 pub use param::*;
+pub use project::*;
 pub use subdiv::*;
+pub use winding::*;
@@ -0,0 +1,146 @@
+use super::{parametric, subdivide, BezierCurve};
+use crate::polynomial::Polynomial;
+use cgmath::{BaseFloat, Point2};
+
+/// Returns the parameters in (0, 1) at which the x and/or y derivative of a 2D bézier curve
+/// vanishes, in ascending order.
+fn derivative_roots<S, L>(curve: &L, x: bool, y: bool) -> Vec<S>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    if curve.count() < 3 {
+        return Vec::new();
+    }
+    let xy = parametric(curve);
+
+    let mut roots = Vec::new();
+    let mut add_roots = |coord: &Polynomial<S>| {
+        let derivative = coord.derivative();
+        roots.extend(
+            derivative
+                .real_roots_in(S::zero(), S::one())
+                .into_iter()
+                .filter(|t| *t > S::zero() && *t < S::one()),
+        );
+    };
+    if x {
+        add_roots(&xy[0]);
+    }
+    if y {
+        add_roots(&xy[1]);
+    }
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots.dedup_by(|a, b| *a - *b <= S::default_epsilon());
+    roots
+}
+
+/// Splits a curve at the given ascending parameters in (0, 1).
+fn split_at<S, L>(curve: &L, params: &[S]) -> Vec<L>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let mut pieces = Vec::with_capacity(params.len() + 1);
+    let mut rest = curve.clone();
+    let mut start = S::zero();
+    for t in params {
+        let (piece, next) = subdivide(&rest, (*t - start) / (S::one() - start));
+        pieces.push(piece);
+        rest = next;
+        start = *t;
+    }
+    pieces.push(rest);
+    pieces
+}
+
+/// Splits a 2D bézier curve into pieces that are monotonic in x, in order.
+///
+/// Adjacent pieces share their endpoints exactly.
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+///
+/// # Details
+/// The curve is split at the roots of the x coordinate of its [`derive`](super::derive)d curve
+/// using [`subdivide`].
+pub fn split_x_monotonic<S, L>(curve: &L) -> Vec<L>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    split_at(curve, &derivative_roots(curve, true, false))
+}
+
+/// Splits a 2D bézier curve into pieces that are monotonic in y, in order; see
+/// [`split_x_monotonic`].
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+pub fn split_y_monotonic<S, L>(curve: &L) -> Vec<L>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    split_at(curve, &derivative_roots(curve, false, true))
+}
+
+/// Splits a 2D bézier curve into pieces that are monotonic in both x and y, in order; see
+/// [`split_x_monotonic`].
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+pub fn split_monotonic<S, L>(curve: &L) -> Vec<L>
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    split_at(curve, &derivative_roots(curve, true, true))
+}
+
+#[test]
+fn test_split_monotonic() {
+    use super::evaluate;
+    use cgmath::assert_abs_diff_eq;
+
+    // monotonic in x, with a maximum in y at t = 0.5
+    let arch = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(3., 2.),
+        Point2::new(4., 0.),
+    ];
+    assert_eq!(split_x_monotonic(&arch).len(), 1);
+    let pieces = split_y_monotonic(&arch);
+    assert_eq!(pieces.len(), 2);
+    assert_abs_diff_eq!(pieces[0][3], Point2::new(2., 1.5), epsilon = 1e-10);
+    assert_eq!(pieces[0][3], pieces[1][0]);
+    assert_eq!(pieces[0][0], arch[0]);
+    assert_eq!(pieces[1][3], arch[3]);
+
+    let curve = vec![
+        Point2::new(0., 0.),
+        Point2::new(4., 3.),
+        Point2::new(-1., 3.),
+        Point2::new(3., 0.),
+        Point2::new(2., -2.),
+    ];
+    let pieces = split_monotonic(&curve);
+    assert!(pieces.len() > 2);
+    for (piece, next) in pieces.iter().zip(pieces.iter().skip(1)) {
+        assert_eq!(piece[piece.len() - 1], next[0]);
+    }
+    for piece in &pieces {
+        // sample each piece and check that coordinates are monotonic
+        let samples: Vec<Point2<f64>> = (0..=20).map(|i| evaluate(piece, i as f64 / 20.)).collect();
+        for axis in 0..2 {
+            let increasing = samples.windows(2).all(|w| w[1][axis] >= w[0][axis] - 1e-12);
+            let decreasing = samples.windows(2).all(|w| w[1][axis] <= w[0][axis] + 1e-12);
+            assert!(increasing || decreasing);
+        }
+    }
+
+    // lines are always monotonic
+    let line = [Point2::new(0., 0.), Point2::new(1., -1.)];
+    assert_eq!(split_monotonic(&line).len(), 1);
+}
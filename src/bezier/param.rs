@@ -1,46 +1,72 @@
-use crate::polynomial::Poly3;
-use cgmath::{BaseFloat, Point2};
+use super::BezierCurve;
+use crate::polynomial::{BernsteinPoly, Poly3, Polynomial};
+use crate::CastError;
+use cgmath::{Array, BaseFloat, Point2};
 
-/// Returns the parametric form of a 2D cubic bézier curve.
+/// Returns the parametric (power basis) form of a bézier curve of any degree, with one polynomial
+/// per coordinate.
 ///
-/// Returns two polynomials for (x, y).
-pub fn parametric_cubic<S>(curve: [Point2<S>; 4]) -> (Poly3<S>, Poly3<S>)
+/// Each polynomial has as many coefficients as the curve has points; the coefficient of tⁱ is
+/// at index i.
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+///
+/// # Details
+/// The coordinates of the control points are the coefficients in the Bernstein basis, which are
+/// converted with [`BernsteinPoly::to_power`].
+pub fn parametric<S, P, L>(curve: &L) -> Vec<Polynomial<S>>
 where
+    L: BezierCurve<P>,
+    P: Array<Element = S>,
     S: BaseFloat,
 {
-    // (1-t)^3 A + 3t(1-t)^2 B + 3t^2(1-t) C + t^3 D
-    // = (-t^3 + 3t^2 - 3t + 1) A
-    //   + (3t^3 - 6t^2 + 3t) B
-    //   + (-3t^3 + 3t^2) C
-    //   + t^3 D
+    try_parametric(curve).expect("could not cast point count to scalar type")
+}
 
-    let n3 = S::from(3).unwrap();
-    let n6 = S::from(6).unwrap();
+/// Returns the parametric form of a bézier curve; see [`parametric`].
+pub(crate) fn try_parametric<S, P, L>(curve: &L) -> Result<Vec<Polynomial<S>>, CastError>
+where
+    L: BezierCurve<P>,
+    P: Array<Element = S>,
+    S: BaseFloat,
+{
+    (0..P::len())
+        .map(|axis| {
+            let coords: Vec<S> = (0..curve.count()).map(|i| curve.get(i)[axis]).collect();
+            BernsteinPoly::from(coords).try_to_power()
+        })
+        .collect()
+}
 
-    let [a, b, c, d] = curve;
-    let x_ttt = -a.x + n3 * b.x - n3 * c.x + d.x;
-    let y_ttt = -a.y + n3 * b.y - n3 * c.y + d.y;
-    let x_tt = n3 * a.x - n6 * b.x + n3 * c.x;
-    let y_tt = n3 * a.y - n6 * b.y + n3 * c.y;
-    let x_t = -n3 * a.x + n3 * b.x;
-    let y_t = -n3 * a.y + n3 * b.y;
-    let x_k = a.x;
-    let y_k = a.y;
+/// Returns the parametric form of a 2D cubic bézier curve.
+///
+/// Returns two polynomials for (x, y); see [`parametric`] for other degrees and dimensions.
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+pub fn parametric_cubic<S>(curve: [Point2<S>; 4]) -> (Poly3<S>, Poly3<S>)
+where
+    S: BaseFloat,
+{
+    try_parametric_cubic(curve).expect("could not cast point count to scalar type")
+}
 
-    (
-        Poly3 {
-            k: x_k,
-            x: x_t,
-            xx: x_tt,
-            xxx: x_ttt,
-        },
-        Poly3 {
-            k: y_k,
-            x: y_t,
-            xx: y_tt,
-            xxx: y_ttt,
-        },
-    )
+/// Returns the parametric form of a 2D cubic bézier curve; see [`parametric_cubic`].
+pub(crate) fn try_parametric_cubic<S>(
+    curve: [Point2<S>; 4],
+) -> Result<(Poly3<S>, Poly3<S>), CastError>
+where
+    S: BaseFloat,
+{
+    let poly3 = |p: &Polynomial<S>| Poly3 {
+        k: p.coeffs[0],
+        x: p.coeffs[1],
+        xx: p.coeffs[2],
+        xxx: p.coeffs[3],
+    };
+    let xy = try_parametric(&curve)?;
+    Ok((poly3(&xy[0]), poly3(&xy[1])))
 }
 
 #[test]
@@ -68,3 +94,59 @@ fn test_parametric_cubic() {
         assert_relative_eq!(p.y, p_y, epsilon = 1e-8);
     }
 }
+
+#[test]
+fn test_parametric() {
+    use super::evaluate;
+    use cgmath::{assert_abs_diff_eq, Point3, Vector2};
+
+    let quadratic = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(2., 0.),
+    ];
+    let quintic = vec![
+        Point2::new(0., 0.),
+        Point2::new(1., 3.),
+        Point2::new(2., -1.),
+        Point2::new(4., 2.),
+        Point2::new(3., 5.),
+        Point2::new(6., 1.),
+    ];
+    let spatial = [
+        Point3::new(1., 0., 0.),
+        Point3::new(2., 1., -1.),
+        Point3::new(0., 3., 2.),
+        Point3::new(1., 1., 4.),
+    ];
+    let vectors = vec![Vector2::new(1., -1.), Vector2::new(3., 2.)];
+
+    let p = parametric(&quadratic);
+    assert_eq!(p.len(), 2);
+    assert_eq!(p[0], Polynomial::from(vec![0., 2.]));
+    assert_eq!(p[1], Polynomial::from(vec![0., 4., -4.]));
+    let p5 = parametric(&quintic);
+    assert_eq!(p5[0].coeffs.len(), 6);
+    let p3 = parametric(&spatial);
+    assert_eq!(p3.len(), 3);
+    let pv = parametric(&vectors);
+
+    for i in 0..=10 {
+        let t = i as f64 / 10.;
+        let q = evaluate(&quintic, t);
+        assert_abs_diff_eq!(
+            Point2::new(p5[0].eval(t), p5[1].eval(t)),
+            q,
+            epsilon = 1e-12
+        );
+        let q = evaluate(&spatial, t);
+        let r = Point3::new(p3[0].eval(t), p3[1].eval(t), p3[2].eval(t));
+        assert_abs_diff_eq!(r, q, epsilon = 1e-12);
+        let q = evaluate(&vectors, t);
+        assert_abs_diff_eq!(
+            Vector2::new(pv[0].eval(t), pv[1].eval(t)),
+            q,
+            epsilon = 1e-12
+        );
+    }
+}
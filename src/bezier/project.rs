@@ -0,0 +1,123 @@
+use super::param::try_parametric;
+use super::{evaluate, BezierCurve};
+use crate::polynomial::Polynomial;
+use crate::CastError;
+use cgmath::{BaseFloat, EuclideanSpace, MetricSpace};
+
+/// The closest point on a curve to some other point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projection<S, P> {
+    /// Curve parameter of the closest point.
+    pub t: S,
+    /// The closest point on the curve.
+    pub point: P,
+    /// Distance from the closest point to the projected point.
+    pub distance: S,
+}
+
+/// Projects a point onto a bézier curve, i.e. finds the closest point on the curve.
+///
+/// If there are several closest points at the same distance, any of them may be returned.
+///
+/// # Panics
+/// - if the number of points cannot be represented by the scalar type S
+///
+/// # Details
+/// For a curve B of degree n, the closest point is either an endpoint or a root of the polynomial
+/// (B(t) - p) · B'(t) of degree 2n - 1, so all local minima are found and compared.
+pub fn project_point<S, P, L>(points: &L, p: P) -> Projection<S, P>
+where
+    L: BezierCurve<P>,
+    P: EuclideanSpace<Scalar = S> + MetricSpace<Metric = S>,
+    S: BaseFloat,
+{
+    try_project_point(points, p).expect("could not cast point count to scalar type")
+}
+
+/// Projects a point onto a bézier curve; see [`project_point`].
+pub(crate) fn try_project_point<S, P, L>(points: &L, p: P) -> Result<Projection<S, P>, CastError>
+where
+    L: BezierCurve<P>,
+    P: EuclideanSpace<Scalar = S> + MetricSpace<Metric = S>,
+    S: BaseFloat,
+{
+    let dot_product = try_parametric(points)?.into_iter().enumerate().fold(
+        Polynomial::default(),
+        |acc, (axis, coord)| {
+            let derivative = coord.derivative();
+            acc + (coord - Polynomial::from(vec![p[axis]])) * derivative
+        },
+    );
+
+    let mut candidates = dot_product.real_roots_in(S::zero(), S::one());
+    candidates.push(S::zero());
+    candidates.push(S::one());
+
+    let closest = candidates
+        .into_iter()
+        .map(|t| {
+            let point = evaluate(points, t);
+            Projection {
+                t,
+                point,
+                distance: point.distance(p),
+            }
+        })
+        .fold(
+            None,
+            |closest: Option<Projection<S, P>>, candidate| match closest {
+                Some(closest) if closest.distance <= candidate.distance => Some(closest),
+                _ => Some(candidate),
+            },
+        )
+        .unwrap();
+    Ok(closest)
+}
+
+#[test]
+fn test_project_point() {
+    use cgmath::assert_abs_diff_eq;
+    use cgmath::{Point2, Point3};
+
+    let curve = [
+        Point2::new(0., 0.),
+        Point2::new(5., 11.),
+        Point2::new(7., 2.),
+        Point2::new(16., 0.),
+    ];
+
+    // points on the curve project onto themselves
+    for i in 0..=10 {
+        let t = i as f64 / 10.;
+        let projection = project_point(&curve, evaluate(&curve, t));
+        assert_abs_diff_eq!(projection.t, t, epsilon = 1e-6);
+        assert_abs_diff_eq!(projection.distance, 0., epsilon = 1e-6);
+    }
+
+    // compare against dense sampling, including points with several local minima
+    let samples: Vec<_> = (0..=10_000)
+        .map(|i| evaluate(&curve, i as f64 / 10_000.))
+        .collect();
+    for p in [
+        Point2::new(6., 4.),
+        Point2::new(3., 9.),
+        Point2::new(-2., 1.),
+        Point2::new(20., -3.),
+        Point2::new(8., 0.),
+    ]
+    .iter()
+    {
+        let projection = project_point(&curve, *p);
+        let min = samples
+            .iter()
+            .map(|q| q.distance(*p))
+            .fold(f64::INFINITY, f64::min);
+        assert_abs_diff_eq!(projection.distance, min, epsilon = 1e-4);
+        assert_abs_diff_eq!(projection.point, evaluate(&curve, projection.t));
+    }
+
+    let line = [Point3::new(0., 0., 0.), Point3::new(2., 2., 2.)];
+    let projection = project_point(&line, Point3::new(1., 0., 2.));
+    assert_abs_diff_eq!(projection.t, 0.5, epsilon = 1e-10);
+    assert_abs_diff_eq!(projection.distance, 2_f64.sqrt(), epsilon = 1e-10);
+}
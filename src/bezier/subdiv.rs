@@ -1,4 +1,5 @@
 use super::BezierCurve;
+use cgmath::{BaseFloat, Point2};
 use std::ops;
 
 /// Splits a bézier curve at t to create two new bézier curves.
@@ -46,6 +47,19 @@ where
     (points_a, points_b)
 }
 
+/// Returns the part of a curve in the parameter range [t0, t1], where t0 ≤ t1.
+pub(crate) fn subdivide_range<S>(points: &[Point2<S>], t0: S, t1: S) -> Vec<Point2<S>>
+where
+    S: BaseFloat,
+{
+    let (_, right) = subdivide(&points.to_vec(), t0);
+    if t0 >= S::one() {
+        return right;
+    }
+    let (left, _) = subdivide(&right, (t1 - t0) / (S::one() - t0));
+    left
+}
+
 #[test]
 fn test_subdiv() {
     use super::evaluate;
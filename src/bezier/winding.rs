@@ -0,0 +1,209 @@
+use super::{evaluate, project_point, split_y_monotonic, BezierCurve};
+use cgmath::{BaseFloat, Point2};
+
+/// Maximum number of bisection steps when locating a crossing.
+const CROSSING_MAX_ITERATIONS: usize = 128;
+
+/// Returns the signed number of times a curve crosses the ray from `point` towards +x. Upward
+/// crossings count as +1, and downward crossings as -1.
+///
+/// The curve is split into pieces that are monotonic in y (see [`split_y_monotonic`]). A piece
+/// crosses the ray if exactly one of its endpoints lies strictly above the point, so that vertices
+/// and horizontal tangents on the ray are counted consistently.
+fn crossings<S, L>(curve: &L, point: Point2<S>) -> i32
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    if curve.count() < 2 {
+        return 0;
+    }
+    split_y_monotonic(curve)
+        .iter()
+        .map(|piece| piece_crossing(piece, point))
+        .sum()
+}
+
+/// Returns the signed crossing of a piece of a curve that is monotonic in y; see [`crossings`].
+fn piece_crossing<S, L>(piece: &L, point: Point2<S>) -> i32
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let above = |p: Point2<S>| p.y > point.y;
+    let upward = above(*piece.get(piece.count() - 1));
+    if above(*piece.get(0)) == upward {
+        return 0;
+    }
+    // bisect to the crossing, keeping above(t0) != above(t1)
+    let (mut t0, mut t1) = (S::zero(), S::one());
+    for _ in 0..CROSSING_MAX_ITERATIONS {
+        let mid = (t0 + t1) / (S::one() + S::one());
+        if mid <= t0 || mid >= t1 {
+            break;
+        }
+        if above(evaluate(piece, mid)) == upward {
+            t1 = mid;
+        } else {
+            t0 = mid;
+        }
+    }
+    match (evaluate(piece, t1).x > point.x, upward) {
+        (false, _) => 0,
+        (true, true) => 1,
+        (true, false) => -1,
+    }
+}
+
+/// Returns the winding number of a closed path around a point.
+///
+/// The path is a sequence of 2D bézier curves, each ending where the next one starts, and the
+/// last one ending where the first one starts. It may also consist of several such loops.
+/// Counterclockwise loops count positively.
+///
+/// The winding number of points on the path is not well-defined; the result is then that of a
+/// point infinitesimally close to it. See [`contains_nonzero`] and [`contains_even_odd`] for tests
+/// that include the path itself.
+///
+/// # Panics
+/// - if the number of points of a curve cannot be represented by the scalar type S
+pub fn winding_number<S, L>(path: &[L], point: Point2<S>) -> i32
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    path.iter().map(|curve| crossings(curve, point)).sum()
+}
+
+/// Returns whether a point lies on a path, within a tolerance relative to its size.
+fn on_path<S, L>(path: &[L], point: Point2<S>) -> bool
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    let scale = path
+        .iter()
+        .flat_map(|curve| (0..curve.count()).map(move |i| *curve.get(i)))
+        .fold(S::one(), |m, p| m.max(p.x.abs()).max(p.y.abs()));
+    let tolerance = scale * S::default_epsilon().sqrt();
+    path.iter()
+        .filter(|curve| curve.count() > 0)
+        .any(|curve| project_point(curve, point).distance <= tolerance)
+}
+
+/// Returns whether a closed path contains a point using the nonzero rule, i.e. if the winding
+/// number is not zero. Points on the path are contained.
+///
+/// See [`winding_number`] for the requirements on the path.
+///
+/// # Panics
+/// - if the number of points of a curve cannot be represented by the scalar type S
+pub fn contains_nonzero<S, L>(path: &[L], point: Point2<S>) -> bool
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    winding_number(path, point) != 0 || on_path(path, point)
+}
+
+/// Returns whether a closed path contains a point using the even-odd rule, i.e. if the winding
+/// number is odd. Points on the path are contained.
+///
+/// See [`winding_number`] for the requirements on the path.
+///
+/// # Panics
+/// - if the number of points of a curve cannot be represented by the scalar type S
+pub fn contains_even_odd<S, L>(path: &[L], point: Point2<S>) -> bool
+where
+    L: BezierCurve<Point2<S>>,
+    S: BaseFloat,
+{
+    winding_number(path, point) % 2 != 0 || on_path(path, point)
+}
+
+#[test]
+fn test_winding_number() {
+    // counterclockwise circle of radius r made of cubics
+    let circle = |r: f64| {
+        let k = 0.5522847498 * r;
+        vec![
+            [
+                Point2::new(r, 0.),
+                Point2::new(r, k),
+                Point2::new(k, r),
+                Point2::new(0., r),
+            ],
+            [
+                Point2::new(0., r),
+                Point2::new(-k, r),
+                Point2::new(-r, k),
+                Point2::new(-r, 0.),
+            ],
+            [
+                Point2::new(-r, 0.),
+                Point2::new(-r, -k),
+                Point2::new(-k, -r),
+                Point2::new(0., -r),
+            ],
+            [
+                Point2::new(0., -r),
+                Point2::new(k, -r),
+                Point2::new(r, -k),
+                Point2::new(r, 0.),
+            ],
+        ]
+    };
+
+    let path = circle(1.);
+    assert_eq!(winding_number(&path, Point2::new(0., 0.)), 1);
+    assert_eq!(winding_number(&path, Point2::new(0.2, -0.3)), 1);
+    assert_eq!(winding_number(&path, Point2::new(2., 0.)), 0);
+    assert_eq!(winding_number(&path, Point2::new(0., 1.5)), 0);
+    // the ray passes through the vertex at (1, 0)
+    assert_eq!(winding_number(&path, Point2::new(0.5, 0.)), 1);
+    assert_eq!(winding_number(&path, Point2::new(-2., 0.)), 0);
+    // the ray touches horizontal tangents at (0, ±1)
+    assert_eq!(winding_number(&path, Point2::new(-2., 1.)), 0);
+    assert_eq!(winding_number(&path, Point2::new(-2., -1.)), 0);
+    assert!(!contains_nonzero(&path, Point2::new(-2., 1.)));
+
+    // points on the path
+    assert!(contains_nonzero(&path, Point2::new(1., 0.)));
+    assert!(contains_nonzero(&path, Point2::new(0., -1.)));
+    assert!(contains_even_odd(&path, Point2::new(-1., 0.)));
+    let on_curve = super::evaluate(&path[1], 0.3);
+    assert!(contains_nonzero(&path, on_curve));
+    assert!(contains_even_odd(&path, on_curve));
+
+    // clockwise
+    let reversed: Vec<[Point2<f64>; 4]> = path
+        .iter()
+        .rev()
+        .map(|c| [c[3], c[2], c[1], c[0]])
+        .collect();
+    assert_eq!(winding_number(&reversed, Point2::new(0., 0.)), -1);
+    assert!(contains_nonzero(&reversed, Point2::new(0., 0.)));
+
+    // two concentric circles
+    let mut path = circle(1.);
+    path.extend(circle(2.));
+    assert_eq!(winding_number(&path, Point2::new(0., 0.)), 2);
+    assert!(contains_nonzero(&path, Point2::new(0., 0.)));
+    assert!(!contains_even_odd(&path, Point2::new(0., 0.)));
+    assert!(contains_even_odd(&path, Point2::new(1.5, 0.)));
+
+    // unit square made of lines, with horizontal edges on the ray
+    let square = vec![
+        [Point2::new(0., 0.), Point2::new(1., 0.)],
+        [Point2::new(1., 0.), Point2::new(1., 1.)],
+        [Point2::new(1., 1.), Point2::new(0., 1.)],
+        [Point2::new(0., 1.), Point2::new(0., 0.)],
+    ];
+    assert_eq!(winding_number(&square, Point2::new(0.5, 0.5)), 1);
+    assert_eq!(winding_number(&square, Point2::new(-1., 0.)), 0);
+    assert_eq!(winding_number(&square, Point2::new(-1., 1.)), 0);
+    assert!(!contains_nonzero(&square, Point2::new(-1., 0.)));
+    assert!(contains_nonzero(&square, Point2::new(0.5, 0.)));
+    assert!(contains_nonzero(&square, Point2::new(0., 0.)));
+    assert!(!contains_nonzero(&square, Point2::new(1.5, 0.5)));
+}
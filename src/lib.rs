@@ -1,4 +1,18 @@
 //! Algorithms for CG geometry
 
+use std::fmt;
+
 pub mod bezier;
 pub mod polynomial;
+
+/// Error returned when a number cannot be represented by the scalar type in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CastError;
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not cast number to scalar type")
+    }
+}
+
+impl std::error::Error for CastError {}
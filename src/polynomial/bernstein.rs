@@ -0,0 +1,415 @@
+use super::Polynomial;
+use crate::CastError;
+use cgmath::num_traits::Float;
+use std::ops;
+
+/// Polynomial in the Bernstein basis of its degree on [0, 1]
+///
+/// `coeffs[i]` is the coefficient of the basis polynomial C(n, i) tⁱ (1 - t)ⁿ⁻ⁱ, where n is the
+/// degree. These are the control values of a bézier curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BernsteinPoly<S> {
+    pub coeffs: Vec<S>,
+}
+
+impl<S> Default for BernsteinPoly<S> {
+    fn default() -> Self {
+        BernsteinPoly { coeffs: Vec::new() }
+    }
+}
+
+impl<S> From<Vec<S>> for BernsteinPoly<S> {
+    fn from(coeffs: Vec<S>) -> Self {
+        BernsteinPoly { coeffs }
+    }
+}
+
+/// Returns the binomial coefficient C(n, k), computed natively in S.
+pub(crate) fn try_binomial<S: Float>(n: usize, k: usize) -> Result<S, CastError> {
+    (1..=k).try_fold(S::one(), |acc, i| {
+        Ok(acc * S::from(n + 1 - i).ok_or(CastError)? / S::from(i).ok_or(CastError)?)
+    })
+}
+
+/// Returns the binomial coefficient C(n, k).
+///
+/// # Panics
+/// - if a factor cannot be represented by the scalar type S
+fn binomial<S: Float>(n: usize, k: usize) -> S {
+    try_binomial(n, k).expect("could not cast binomial coefficients to scalar type")
+}
+
+/// Returns the rows 0 to n of Pascal's triangle, where `rows[i][k]` is C(i, k).
+fn pascal_triangle<S: Float>(n: usize) -> Vec<Vec<S>> {
+    let mut rows: Vec<Vec<S>> = Vec::with_capacity(n + 1);
+    for i in 0..=n {
+        let row = (0..=i)
+            .map(|k| {
+                if k == 0 || k == i {
+                    S::one()
+                } else {
+                    rows[i - 1][k - 1] + rows[i - 1][k]
+                }
+            })
+            .collect();
+        rows.push(row);
+    }
+    rows
+}
+
+impl<S> From<Polynomial<S>> for BernsteinPoly<S>
+where
+    S: Float,
+{
+    /// Converts a polynomial from the power basis, keeping its length.
+    ///
+    /// # Details
+    /// The i-th coefficient is Σₖ C(i, k) / C(n, k) aₖ, summing over k ≤ i.
+    fn from(p: Polynomial<S>) -> Self {
+        let n = p.coeffs.len().saturating_sub(1);
+        let pascal = pascal_triangle::<S>(n);
+        let coeffs = (0..p.coeffs.len())
+            .map(|i| {
+                p.coeffs
+                    .iter()
+                    .zip(&pascal[i])
+                    .zip(&pascal[n])
+                    .fold(S::zero(), |acc, ((c, c_i), c_n)| acc + *c * *c_i / *c_n)
+            })
+            .collect();
+        BernsteinPoly { coeffs }
+    }
+}
+
+impl<S> From<BernsteinPoly<S>> for Polynomial<S>
+where
+    S: Float,
+{
+    /// Converts a polynomial to the power basis.
+    ///
+    /// # Panics
+    /// - if the length cannot be represented by the scalar type S
+    fn from(p: BernsteinPoly<S>) -> Self {
+        p.to_power()
+    }
+}
+
+impl<S> BernsteinPoly<S>
+where
+    S: Float,
+{
+    /// Returns the degree of the basis, which may exceed the actual degree.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// Evaluates the polynomial using de Casteljau’s algorithm.
+    pub fn eval(&self, t: S) -> S {
+        self.blossom(&vec![t; self.degree()])
+    }
+
+    /// Evaluates the blossom, i.e. de Casteljau’s algorithm with parameter `params[i]` in step i.
+    fn blossom(&self, params: &[S]) -> S {
+        if self.coeffs.is_empty() {
+            return S::zero();
+        }
+        let mut values = self.coeffs.clone();
+        for (step, t) in params.iter().enumerate() {
+            for i in 0..values.len() - step - 1 {
+                values[i] = values[i] * (S::one() - *t) + values[i + 1] * *t;
+            }
+        }
+        values[0]
+    }
+
+    /// Converts to the power basis, keeping the length; see [`Polynomial`].
+    ///
+    /// # Panics
+    /// - if the length cannot be represented by the scalar type S
+    pub fn to_power(&self) -> Polynomial<S> {
+        self.try_to_power()
+            .expect("could not cast binomial coefficients to scalar type")
+    }
+
+    /// Converts to the power basis; see [`to_power`](Self::to_power).
+    ///
+    /// # Details
+    /// The coefficient of tᵏ is C(n, k) Σᵢ (-1)ᵏ⁻ⁱ C(k, i) bᵢ, summing over i ≤ k.
+    pub(crate) fn try_to_power(&self) -> Result<Polynomial<S>, CastError> {
+        let n = self.degree();
+        let coeffs = (0..self.coeffs.len())
+            .map(|k| {
+                let mut sum = S::zero();
+                for (i, c) in self.coeffs.iter().enumerate().take(k + 1) {
+                    let term = try_binomial::<S>(k, i)? * *c;
+                    sum = if (k - i) % 2 == 0 {
+                        sum + term
+                    } else {
+                        sum - term
+                    };
+                }
+                Ok(try_binomial::<S>(n, k)? * sum)
+            })
+            .collect::<Result<_, CastError>>()?;
+        Ok(Polynomial { coeffs })
+    }
+
+    /// Returns the same polynomial in the Bernstein basis of a degree higher by `by`.
+    ///
+    /// # Panics
+    /// - if the elevated length cannot be represented by the scalar type S
+    pub fn elevate(&self, by: usize) -> Self {
+        self.try_elevate(by)
+            .expect("could not cast binomial coefficients to scalar type")
+    }
+
+    /// Elevates the degree; see [`elevate`](Self::elevate).
+    pub(crate) fn try_elevate(&self, by: usize) -> Result<Self, CastError> {
+        if by == 0 || self.coeffs.is_empty() {
+            return Ok(self.clone());
+        }
+        let n = self.degree();
+        let coeffs = (0..=n + by)
+            .map(|k| {
+                let first = k.saturating_sub(by);
+                let mut sum = S::zero();
+                for i in first..=k.min(n) {
+                    sum = sum
+                        + self.coeffs[i] * try_binomial::<S>(n, i)? * try_binomial::<S>(by, k - i)?;
+                }
+                Ok(sum / try_binomial::<S>(n + by, k)?)
+            })
+            .collect::<Result<_, CastError>>()?;
+        Ok(BernsteinPoly { coeffs })
+    }
+
+    /// Returns the derivative, which has a degree lower by one.
+    ///
+    /// # Panics
+    /// - if the degree cannot be represented by the scalar type S
+    pub fn derivative(&self) -> Self {
+        self.try_derivative()
+            .expect("could not cast degree to scalar type")
+    }
+
+    /// Returns the derivative; see [`derivative`](Self::derivative).
+    pub(crate) fn try_derivative(&self) -> Result<Self, CastError> {
+        let n = S::from(self.degree()).ok_or(CastError)?;
+        let coeffs = self.coeffs.windows(2).map(|w| (w[1] - w[0]) * n).collect();
+        Ok(BernsteinPoly { coeffs })
+    }
+
+    /// Returns the polynomial on [a, b], reparametrized to [0, 1].
+    ///
+    /// The interval may lie outside [0, 1], and a > b reverses the direction.
+    ///
+    /// # Details
+    /// The k-th coefficient is the blossom evaluated at n - k copies of a and k copies of b.
+    pub fn subdivide(&self, a: S, b: S) -> Self {
+        let n = self.degree();
+        let coeffs = (0..self.coeffs.len())
+            .map(|k| {
+                let mut params = vec![a; n - k];
+                params.resize(n, b);
+                self.blossom(&params)
+            })
+            .collect();
+        BernsteinPoly { coeffs }
+    }
+
+    /// Returns an interval in [0, 1] that contains all roots in [0, 1], or None if there are
+    /// none.
+    ///
+    /// The interval is the intersection of the t-axis with the convex hull of the control points
+    /// (i / n, `coeffs[i]`). The zero polynomial yields [0, 1].
+    ///
+    /// # Panics
+    /// - if the degree cannot be represented by the scalar type S
+    pub fn root_bounds(&self) -> Option<(S, S)> {
+        self.try_root_bounds()
+            .expect("could not cast degree to scalar type")
+    }
+
+    /// Returns an interval that contains all roots in [0, 1]; see
+    /// [`root_bounds`](Self::root_bounds).
+    pub(crate) fn try_root_bounds(&self) -> Result<Option<(S, S)>, CastError> {
+        let n = self.degree();
+        if n == 0 {
+            return Ok(match self.coeffs.first() {
+                Some(c) if !c.is_zero() => None,
+                _ => Some((S::zero(), S::one())),
+            });
+        }
+        let n_s = S::from(n).ok_or(CastError)?;
+        let xs = (0..=n)
+            .map(|i| Ok(S::from(i).ok_or(CastError)? / n_s))
+            .collect::<Result<Vec<S>, CastError>>()?;
+        let x = |i: usize| xs[i];
+        let mut bounds: Option<(S, S)> = None;
+        let mut include = |t: S| {
+            bounds = Some(match bounds {
+                Some((min, max)) => (min.min(t), max.max(t)),
+                None => (t, t),
+            });
+        };
+        for (i, ci) in self.coeffs.iter().enumerate() {
+            if ci.is_zero() {
+                include(x(i));
+            }
+            for (j, cj) in self.coeffs.iter().enumerate().skip(i + 1) {
+                if (*ci < S::zero() && *cj > S::zero()) || (*ci > S::zero() && *cj < S::zero()) {
+                    include(x(i) + (x(j) - x(i)) * *ci / (*ci - *cj));
+                }
+            }
+        }
+        Ok(bounds)
+    }
+}
+
+impl<S> ops::Add for BernsteinPoly<S>
+where
+    S: Float,
+{
+    type Output = Self;
+
+    /// Adds two polynomials, elevating the one of lower degree.
+    fn add(self, rhs: Self) -> Self {
+        let (long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        if short.coeffs.is_empty() {
+            return long;
+        }
+        let short = short.elevate(long.degree() - short.degree());
+        let coeffs = long
+            .coeffs
+            .into_iter()
+            .zip(short.coeffs)
+            .map(|(a, b)| a + b)
+            .collect();
+        BernsteinPoly { coeffs }
+    }
+}
+
+impl<S> ops::Neg for BernsteinPoly<S>
+where
+    S: Copy + ops::Neg<Output = S>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        BernsteinPoly {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<S> ops::Sub for BernsteinPoly<S>
+where
+    S: Float,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<S> ops::Mul<S> for BernsteinPoly<S>
+where
+    S: Copy + ops::Mul<S, Output = S>,
+{
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self {
+        BernsteinPoly {
+            coeffs: self.coeffs.into_iter().map(|c| c * rhs).collect(),
+        }
+    }
+}
+
+impl<S> ops::Mul for BernsteinPoly<S>
+where
+    S: Float,
+{
+    type Output = Self;
+
+    /// Multiplies two polynomials; the degree of the product is the sum of the degrees.
+    fn mul(self, rhs: Self) -> Self {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return BernsteinPoly::default();
+        }
+        let (m, n) = (self.degree(), rhs.degree());
+        let mut coeffs = vec![S::zero(); m + n + 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j] + *a * *b * binomial::<S>(m, i) * binomial::<S>(n, j);
+            }
+        }
+        for (k, c) in coeffs.iter_mut().enumerate() {
+            *c = *c / binomial::<S>(m + n, k);
+        }
+        BernsteinPoly { coeffs }
+    }
+}
+
+#[test]
+fn test_bernstein_poly() {
+    use cgmath::assert_abs_diff_eq;
+
+    let power = Polynomial::from(vec![1., -3., 0., 2.]);
+    let p = BernsteinPoly::from(power.clone());
+    let q = BernsteinPoly::from(vec![2., -1., 0.5]);
+    assert_eq!(p.degree(), 3);
+    assert_eq!(p.to_power().coeffs.len(), 4);
+
+    let ts = [-0.5, 0., 0.3, 0.7, 1., 1.5];
+    for &t in ts.iter() {
+        let (pt, qt) = (p.eval(t), q.eval(t));
+        assert_abs_diff_eq!(pt, power.eval(t), epsilon = 1e-12);
+        assert_abs_diff_eq!(p.to_power().eval(t), pt, epsilon = 1e-12);
+        assert_abs_diff_eq!(q.to_power().eval(t), qt, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.elevate(2).eval(t), pt, epsilon = 1e-12);
+        assert_abs_diff_eq!((p.clone() + q.clone()).eval(t), pt + qt, epsilon = 1e-12);
+        assert_abs_diff_eq!((q.clone() - p.clone()).eval(t), qt - pt, epsilon = 1e-12);
+        assert_abs_diff_eq!((p.clone() * q.clone()).eval(t), pt * qt, epsilon = 1e-12);
+        assert_abs_diff_eq!((q.clone() * 2.).eval(t), qt * 2., epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            p.derivative().eval(t),
+            power.derivative().eval(t),
+            epsilon = 1e-12
+        );
+    }
+    assert_eq!(p.elevate(2).degree(), 5);
+    assert_eq!((p.clone() * q.clone()).degree(), 5);
+    assert_eq!(p.derivative().degree(), 2);
+    // (1 - t)² - t² = 1 - 2t
+    assert_eq!(
+        BernsteinPoly::from(vec![1., 0., -1.]).to_power().coeffs,
+        vec![1., -2., 0.]
+    );
+
+    // subdivision, including intervals outside [0, 1] and reversed ones
+    for &(a, b) in [(0., 0.5), (0.25, 1.), (-1., 2.), (0.8, 0.1)].iter() {
+        let sub = p.subdivide(a, b);
+        for &s in ts.iter() {
+            assert_abs_diff_eq!(sub.eval(s), p.eval(a + (b - a) * s), epsilon = 1e-12);
+        }
+    }
+    let (left, right) = (p.subdivide(0., 0.5), p.subdivide(0.5, 1.));
+    assert_eq!(left.coeffs[3], right.coeffs[0]);
+    assert_eq!(left.coeffs[0], p.coeffs[0]);
+
+    // roots of 2t³ - 3t + 1 = (t - 1)(2t² + 2t - 1) in [0, 1] are (√3 - 1) / 2 and 1
+    let (min, max) = p.root_bounds().unwrap();
+    assert!(min <= (3_f64.sqrt() - 1.) / 2.);
+    assert!(min >= 0.);
+    assert_eq!(max, 1.);
+    let shifted = p.clone() + BernsteinPoly::from(vec![2.]);
+    assert!(shifted.root_bounds().is_none());
+    assert_eq!(
+        BernsteinPoly::from(vec![-1., 1.]).root_bounds(),
+        Some((0.5, 0.5))
+    );
+    assert_eq!(BernsteinPoly::from(vec![0.]).root_bounds(), Some((0., 1.)));
+    assert!(BernsteinPoly::from(vec![3.]).root_bounds().is_none());
+}
@@ -0,0 +1,705 @@
+//! Utilities for dealing with polynomials
+
+use cgmath::num_traits::Float;
+use cgmath::Zero;
+use std::ops;
+
+mod bernstein;
+mod multivariate;
+mod roots;
+mod univariate;
+
+pub use bernstein::*;
+pub use multivariate::*;
+pub use roots::*;
+pub use univariate::*;
+
+/// Third degree polynomial
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct Poly3<S> {
+    // SAFETY: do not modify layout!
+    pub k: S,
+    pub x: S,
+    pub xx: S,
+    pub xxx: S,
+}
+
+/// First degree polynomial in 2 dimensions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Poly1x2d<S> {
+    pub k: S,
+    pub x: S,
+    pub y: S,
+}
+
+/// Second degree polynomial in 2 dimensions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Poly2x2d<S> {
+    pub k: S,
+    pub x: S,
+    pub y: S,
+    pub xy: S,
+    pub xx: S,
+    pub yy: S,
+}
+
+/// Third degree polynomial in 2 dimensions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Poly3x2d<S> {
+    pub k: S,
+    pub x: S,
+    pub y: S,
+    pub xy: S,
+    pub xx: S,
+    pub yy: S,
+    pub xxy: S,
+    pub xyy: S,
+    pub xxx: S,
+    pub yyy: S,
+}
+
+/// Polynomial of arbitrary degree in 2 dimensions
+///
+/// `coeffs[i][j]` is the coefficient of xⁱyʲ; missing coefficients are zero.
+#[derive(Debug, Clone)]
+pub struct PolyNx2d<S> {
+    pub coeffs: Vec<Vec<S>>,
+}
+
+impl<S> Default for PolyNx2d<S> {
+    fn default() -> Self {
+        PolyNx2d { coeffs: Vec::new() }
+    }
+}
+
+impl<S> ops::Add for Poly1x2d<S>
+where
+    S: ops::Add<S, Output = S>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Poly1x2d {
+            k: self.k + rhs.k,
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<S> ops::Sub for Poly1x2d<S>
+where
+    S: ops::Sub<S, Output = S>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Poly1x2d {
+            k: self.k - rhs.k,
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<S> ops::Mul<S> for Poly1x2d<S>
+where
+    S: ops::Mul<S, Output = S> + Copy,
+{
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self {
+        Poly1x2d {
+            k: self.k * rhs,
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<S> ops::Mul<Self> for Poly1x2d<S>
+where
+    S: ops::Mul<S, Output = S> + Copy + Zero,
+{
+    type Output = Poly2x2d<S>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Poly2x2d {
+            k: self.k * rhs.k,
+            x: self.k * rhs.x + self.x * rhs.k,
+            y: self.k * rhs.y + self.y * rhs.k,
+            xx: self.x * rhs.x,
+            xy: self.x * rhs.y + self.y * rhs.x,
+            yy: self.y * rhs.y,
+        }
+    }
+}
+
+impl<S> ops::Mul<Poly2x2d<S>> for Poly1x2d<S>
+where
+    S: ops::Mul<S, Output = S> + Copy + Zero,
+{
+    type Output = Poly3x2d<S>;
+    fn mul(self, rhs: Poly2x2d<S>) -> Self::Output {
+        let Poly1x2d { k, x, y } = self;
+        Poly3x2d {
+            k: rhs.k * k,
+            x: rhs.k * x + rhs.x * k,
+            y: rhs.k * y + rhs.y * k,
+            xx: rhs.x * x + rhs.xx * k,
+            xy: rhs.x * y + rhs.y * x + rhs.xy * k,
+            yy: rhs.y * y + rhs.yy * k,
+            xxy: rhs.xx * y + rhs.xy * x,
+            xyy: rhs.xy * y + rhs.yy * x,
+            xxx: rhs.xx * x,
+            yyy: rhs.yy * y,
+        }
+    }
+}
+
+impl<S> ops::Add for Poly2x2d<S>
+where
+    S: ops::Add<S, Output = S>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Poly2x2d {
+            k: self.k + rhs.k,
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            xx: self.xx + rhs.xx,
+            xy: self.xy + rhs.xy,
+            yy: self.yy + rhs.yy,
+        }
+    }
+}
+
+impl<S> ops::Sub for Poly2x2d<S>
+where
+    S: ops::Sub<S, Output = S>,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Poly2x2d {
+            k: self.k - rhs.k,
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            xx: self.xx - rhs.xx,
+            xy: self.xy - rhs.xy,
+            yy: self.yy - rhs.yy,
+        }
+    }
+}
+
+impl<S> ops::Add for Poly3x2d<S>
+where
+    S: ops::Add<S, Output = S>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Poly3x2d {
+            k: self.k + rhs.k,
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            xx: self.xx + rhs.xx,
+            xy: self.xy + rhs.xy,
+            yy: self.yy + rhs.yy,
+            xxy: self.xxy + rhs.xxy,
+            xyy: self.xyy + rhs.xyy,
+            xxx: self.xxx + rhs.xxx,
+            yyy: self.yyy + rhs.yyy,
+        }
+    }
+}
+
+impl<S> ops::Sub for Poly3x2d<S>
+where
+    S: ops::Sub<S, Output = S>,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Poly3x2d {
+            k: self.k - rhs.k,
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            xx: self.xx - rhs.xx,
+            xy: self.xy - rhs.xy,
+            yy: self.yy - rhs.yy,
+            xxy: self.xxy - rhs.xxy,
+            xyy: self.xyy - rhs.xyy,
+            xxx: self.xxx - rhs.xxx,
+            yyy: self.yyy - rhs.yyy,
+        }
+    }
+}
+
+impl<S> ops::Add for PolyNx2d<S>
+where
+    S: Copy + Zero,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let mut coeffs = self.coeffs;
+        if coeffs.len() < rhs.coeffs.len() {
+            coeffs.resize(rhs.coeffs.len(), Vec::new());
+        }
+        for (row, rhs_row) in coeffs.iter_mut().zip(rhs.coeffs) {
+            if row.len() < rhs_row.len() {
+                row.resize(rhs_row.len(), S::zero());
+            }
+            for (c, rhs_c) in row.iter_mut().zip(rhs_row) {
+                *c = *c + rhs_c;
+            }
+        }
+        PolyNx2d { coeffs }
+    }
+}
+
+impl<S> ops::Sub for PolyNx2d<S>
+where
+    S: Copy + Zero + ops::Neg<Output = S>,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<S> ops::Neg for PolyNx2d<S>
+where
+    S: Copy + ops::Neg<Output = S>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        let coeffs = self
+            .coeffs
+            .into_iter()
+            .map(|row| row.into_iter().map(|c| -c).collect())
+            .collect();
+        PolyNx2d { coeffs }
+    }
+}
+
+impl<S> ops::Mul<S> for PolyNx2d<S>
+where
+    S: Copy + ops::Mul<S, Output = S>,
+{
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self {
+        let coeffs = self
+            .coeffs
+            .into_iter()
+            .map(|row| row.into_iter().map(|c| c * rhs).collect())
+            .collect();
+        PolyNx2d { coeffs }
+    }
+}
+
+impl<S> ops::Mul for PolyNx2d<S>
+where
+    S: Copy + Zero + ops::Mul<S, Output = S>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let mut coeffs: Vec<Vec<S>> = Vec::new();
+        for (i, row) in self.coeffs.iter().enumerate() {
+            for (k, rhs_row) in rhs.coeffs.iter().enumerate() {
+                if coeffs.len() < i + k + 1 {
+                    coeffs.resize(i + k + 1, Vec::new());
+                }
+                let out = &mut coeffs[i + k];
+                if out.len() + 1 < row.len() + rhs_row.len() {
+                    out.resize(row.len() + rhs_row.len() - 1, S::zero());
+                }
+                for (j, c) in row.iter().enumerate() {
+                    for (l, rhs_c) in rhs_row.iter().enumerate() {
+                        out[j + l] = out[j + l] + *c * *rhs_c;
+                    }
+                }
+            }
+        }
+        PolyNx2d { coeffs }
+    }
+}
+
+impl<S> From<Poly1x2d<S>> for PolyNx2d<S>
+where
+    S: Copy + Zero,
+{
+    fn from(p: Poly1x2d<S>) -> Self {
+        PolyNx2d {
+            coeffs: vec![vec![p.k, p.y], vec![p.x]],
+        }
+    }
+}
+
+impl<S> From<Poly2x2d<S>> for PolyNx2d<S>
+where
+    S: Copy + Zero,
+{
+    fn from(p: Poly2x2d<S>) -> Self {
+        PolyNx2d {
+            coeffs: vec![vec![p.k, p.y, p.yy], vec![p.x, p.xy], vec![p.xx]],
+        }
+    }
+}
+
+impl<S> From<Poly3x2d<S>> for PolyNx2d<S>
+where
+    S: Copy + Zero,
+{
+    fn from(p: Poly3x2d<S>) -> Self {
+        PolyNx2d {
+            coeffs: vec![
+                vec![p.k, p.y, p.yy, p.yyy],
+                vec![p.x, p.xy, p.xyy],
+                vec![p.xx, p.xxy],
+                vec![p.xxx],
+            ],
+        }
+    }
+}
+
+impl<S> Poly3<S>
+where
+    S: ops::Add<S, Output = S> + ops::Mul<S, Output = S> + Copy,
+{
+    pub fn eval(&self, x: S) -> S {
+        self.k + self.x * x + self.xx * x * x + self.xxx * x * x * x
+    }
+
+    pub fn as_slice(&self) -> &[S; 4] {
+        // SAFETY: struct is repr(C) so the layout is the same
+        unsafe { &*(self as *const Self as *const [S; 4]) }
+    }
+}
+
+impl<S> Poly3<S>
+where
+    S: Float,
+{
+    /// Returns the real roots in ascending order; see [`real_roots_cubic`].
+    pub fn real_roots(&self) -> Vec<S> {
+        real_roots_cubic([self.k, self.x, self.xx, self.xxx])
+    }
+}
+
+impl<S> Poly1x2d<S>
+where
+    S: ops::Add<S, Output = S> + ops::Mul<S, Output = S> + Copy,
+{
+    pub fn eval(&self, x: S, y: S) -> S {
+        self.k + self.x * x + self.y * y
+    }
+}
+
+impl<S> Poly1x2d<S>
+where
+    S: ops::Add<S, Output = S> + ops::Mul<S, Output = S> + Zero + Copy,
+{
+    pub fn subst(&self, x: &[S], y: &[S]) -> Vec<S> {
+        let mut out = vec![S::zero(); x.len().max(y.len()).max(1)];
+        out[0] = self.k;
+        for (deg, xa) in x.iter().enumerate() {
+            out[deg] = out[deg] + self.x * *xa;
+        }
+        for (deg, ya) in y.iter().enumerate() {
+            out[deg] = out[deg] + self.y * *ya;
+        }
+        out
+    }
+}
+
+impl<S> Poly2x2d<S>
+where
+    S: ops::Add<S, Output = S> + ops::Mul<S, Output = S> + Copy,
+{
+    pub fn eval(&self, x: S, y: S) -> S {
+        self.k + self.x * x + self.y * y + self.xx * x * x + self.xy * x * y + self.yy * y * y
+    }
+}
+
+impl<S> Poly2x2d<S>
+where
+    S: ops::Add<S, Output = S> + ops::Mul<S, Output = S> + Zero + Copy,
+{
+    pub fn subst(&self, x: &[S], y: &[S]) -> Vec<S> {
+        let mut out = vec![self.k];
+        let mut add_fac = |deg: usize, a: S| {
+            if out.len() < deg + 1 {
+                out.resize(deg + 1, S::zero());
+            }
+            out[deg] = out[deg] + a;
+        };
+        for (deg, xa) in x.iter().enumerate() {
+            add_fac(deg, self.x * *xa);
+            for (deg2, xb) in x.iter().enumerate() {
+                add_fac(deg + deg2, self.xx * *xa * *xb);
+            }
+            for (deg2, yb) in y.iter().enumerate() {
+                add_fac(deg + deg2, self.xy * *xa * *yb);
+            }
+        }
+        for (deg, ya) in y.iter().enumerate() {
+            add_fac(deg, self.y * *ya);
+            for (deg2, yb) in y.iter().enumerate() {
+                add_fac(deg + deg2, self.yy * *ya * *yb);
+            }
+        }
+        out
+    }
+}
+
+impl<S> Poly3x2d<S>
+where
+    S: ops::Add<S, Output = S> + ops::Mul<S, Output = S> + Copy,
+{
+    pub fn eval(&self, x: S, y: S) -> S {
+        self.k
+            + self.x * x
+            + self.y * y
+            + self.xx * x * x
+            + self.xy * x * y
+            + self.yy * y * y
+            + self.xxy * x * x * y
+            + self.xyy * x * y * y
+            + self.xxx * x * x * x
+            + self.yyy * y * y * y
+    }
+}
+
+impl<S> Poly3x2d<S>
+where
+    S: ops::Add<S, Output = S> + ops::Mul<S, Output = S> + Zero + Copy,
+{
+    pub fn subst(&self, x: &[S], y: &[S]) -> Vec<S> {
+        let mut out = vec![self.k];
+        let mut add_fac = |deg: usize, a: S| {
+            if out.len() < deg + 1 {
+                out.resize(deg + 1, S::zero());
+            }
+            out[deg] = out[deg] + a;
+        };
+        let d = |x: &S| *x;
+        for (deg, xa) in x.iter().map(d).enumerate() {
+            add_fac(deg, self.x * xa);
+            for (deg2, xb) in x.iter().map(d).enumerate() {
+                add_fac(deg + deg2, self.xx * xa * xb);
+                for (deg3, xc) in x.iter().map(d).enumerate() {
+                    add_fac(deg + deg2 + deg3, self.xxx * xa * xb * xc);
+                }
+            }
+        }
+        for (deg, ya) in y.iter().map(d).enumerate() {
+            add_fac(deg, self.y * ya);
+            for (deg2, yb) in y.iter().map(d).enumerate() {
+                add_fac(deg + deg2, self.yy * ya * yb);
+                for (deg3, yc) in y.iter().map(d).enumerate() {
+                    add_fac(deg + deg2 + deg3, self.yyy * ya * yb * yc);
+                }
+            }
+        }
+        for (deg, xa) in x.iter().map(d).enumerate() {
+            for (deg2, ya) in y.iter().map(d).enumerate() {
+                add_fac(deg + deg2, self.xy * xa * ya);
+                for (deg3, xb) in x.iter().map(d).enumerate() {
+                    add_fac(deg + deg2 + deg3, self.xxy * xa * xb * ya);
+                }
+                for (deg3, yb) in y.iter().map(d).enumerate() {
+                    add_fac(deg + deg2 + deg3, self.xyy * xa * ya * yb);
+                }
+            }
+        }
+        out
+    }
+}
+
+impl<S> PolyNx2d<S>
+where
+    S: Copy + Zero + ops::Mul<S, Output = S>,
+{
+    /// Returns the coefficient of xⁱyʲ.
+    pub fn coeff(&self, i: usize, j: usize) -> S {
+        self.coeffs
+            .get(i)
+            .and_then(|row| row.get(j))
+            .copied()
+            .unwrap_or_else(S::zero)
+    }
+
+    /// Returns the total degree, ignoring zero coefficients. The zero polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        let mut degree = 0;
+        for (i, row) in self.coeffs.iter().enumerate() {
+            for (j, c) in row.iter().enumerate() {
+                if !c.is_zero() {
+                    degree = degree.max(i + j);
+                }
+            }
+        }
+        degree
+    }
+
+    pub fn eval(&self, x: S, y: S) -> S {
+        self.coeffs.iter().rev().fold(S::zero(), |acc, row| {
+            acc * x + row.iter().rev().fold(S::zero(), |acc, c| acc * y + *c)
+        })
+    }
+
+    pub fn subst(&self, x: &[S], y: &[S]) -> Vec<S> {
+        // Horner’s method with polynomial coefficients
+        let horner = |acc: Vec<S>, z: &[S], c: S| {
+            if acc.is_empty() {
+                return vec![c];
+            }
+            let mut out = vec![S::zero(); acc.len() + z.len() - 1];
+            for (i, a) in acc.iter().enumerate() {
+                for (j, b) in z.iter().enumerate() {
+                    out[i + j] = out[i + j] + *a * *b;
+                }
+            }
+            out[0] = out[0] + c;
+            out
+        };
+        let mut out = self.coeffs.iter().rev().fold(Vec::new(), |acc, row| {
+            let row = row
+                .iter()
+                .rev()
+                .fold(Vec::new(), |acc, c| horner(acc, y, *c));
+            let mut out = horner(acc, x, S::zero());
+            if out.len() < row.len() {
+                out.resize(row.len(), S::zero());
+            }
+            for (o, r) in out.iter_mut().zip(row) {
+                *o = *o + r;
+            }
+            out
+        });
+        if out.is_empty() {
+            out.push(S::zero());
+        }
+        out
+    }
+}
+
+#[test]
+fn test_poly3x2d_subst() {
+    use cgmath::assert_abs_diff_eq;
+
+    let p = Poly3x2d {
+        k: 3.,
+        x: 2.,
+        y: 5.,
+        xy: -5.,
+        xx: -24.,
+        yy: 3.,
+        xxy: 9.,
+        xyy: -16.,
+        xxx: -44.,
+        yyy: 1.,
+    };
+    let px = Poly3 {
+        k: -4.,
+        x: 1.,
+        xx: 2.,
+        xxx: 3.,
+    };
+    let py = Poly3 {
+        k: 4.,
+        x: -2.,
+        xx: 7.,
+        xxx: 1.,
+    };
+
+    let f_ref = |t: f64| p.eval(px.eval(t), py.eval(t));
+    let f_subst = p.subst(px.as_slice(), py.as_slice());
+
+    fn eval_poly(f: &[f64], t: f64) -> f64 {
+        let mut out = 0.;
+        for (i, a) in f.iter().enumerate() {
+            out += a * t.powf(i as f64);
+        }
+        out
+    }
+
+    for i in 0..10 {
+        let t = i as f64;
+        assert_abs_diff_eq!(f_ref(t), eval_poly(&f_subst, t));
+    }
+}
+
+#[test]
+fn test_poly2x2d_subst() {
+    use cgmath::assert_abs_diff_eq;
+
+    let p = Poly2x2d {
+        k: 3.,
+        x: 2.,
+        y: 5.,
+        xy: -5.,
+        xx: -24.,
+        yy: 3.,
+    };
+    let l = Poly1x2d {
+        k: 1.,
+        x: -2.,
+        y: 4.,
+    };
+    let px = [-4., 1., 2.];
+    let py = [4., -2.];
+
+    let f_subst = p.subst(&px, &py);
+    let l_subst = l.subst(&px, &py);
+    for i in 0..10 {
+        let t = i as f64;
+        let (x, y) = (eval_slice(&px, t), eval_slice(&py, t));
+        assert_abs_diff_eq!(p.eval(x, y), eval_slice(&f_subst, t));
+        assert_abs_diff_eq!(l.eval(x, y), eval_slice(&l_subst, t));
+    }
+}
+
+#[test]
+fn test_poly_nx2d() {
+    use cgmath::assert_abs_diff_eq;
+
+    let a = Poly1x2d {
+        k: 1.,
+        x: 2.,
+        y: -3.,
+    };
+    let b = Poly2x2d {
+        k: 2.,
+        x: -1.,
+        y: 0.5,
+        xy: 3.,
+        xx: -2.,
+        yy: 1.,
+    };
+    let c = a * b;
+    let an = PolyNx2d::from(a);
+    let bn = PolyNx2d::from(b);
+    let cn = an.clone() * bn.clone();
+    assert_eq!(cn.degree(), 3);
+
+    let x = [1., 2., -1.];
+    let y = [0.5, -2., 3.];
+    for &(px, py) in [(0., 0.), (1., 2.), (-1.5, 0.3)].iter() {
+        assert_abs_diff_eq!(cn.eval(px, py), c.eval(px, py), epsilon = 1e-10);
+        assert_abs_diff_eq!(
+            PolyNx2d::from(c).eval(px, py),
+            c.eval(px, py),
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(
+            (an.clone() - bn.clone() * 2.).eval(px, py),
+            a.eval(px, py) - b.eval(px, py) * 2.,
+            epsilon = 1e-10
+        );
+    }
+
+    let subst = cn.subst(&x, &y);
+    let subst_ref = c.subst(&x, &y);
+    assert_eq!(subst.len(), subst_ref.len());
+    for (s, r) in subst.iter().zip(subst_ref.iter()) {
+        assert_abs_diff_eq!(s, r, epsilon = 1e-10);
+    }
+}
@@ -0,0 +1,565 @@
+use super::{Poly1x2d, Poly2x2d, Poly3x2d, PolyNx2d, Polynomial};
+use cgmath::num_traits::Float;
+use cgmath::{One, Zero};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::{fmt, ops};
+
+/// Polynomial of arbitrary degree in an arbitrary number of variables
+///
+/// Terms are stored sparsely by their exponents, where the i-th exponent belongs to the i-th
+/// variable. Exponents have no trailing zeros, and zero coefficients are not stored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPolynomial<S> {
+    terms: BTreeMap<Vec<usize>, S>,
+}
+
+/// Error returned when a polynomial has terms that the target type cannot represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversionError;
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "polynomial has terms not representable by the target type"
+        )
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl<S> Default for MultiPolynomial<S> {
+    fn default() -> Self {
+        MultiPolynomial {
+            terms: BTreeMap::new(),
+        }
+    }
+}
+
+/// Returns the exponents without trailing zeros.
+fn trim_exponents(exponents: &[usize]) -> &[usize] {
+    match exponents.iter().rposition(|e| *e != 0) {
+        Some(last) => &exponents[..=last],
+        None => &[],
+    }
+}
+
+impl<S> MultiPolynomial<S>
+where
+    S: Copy + Zero,
+{
+    /// Returns the monomial c · x₀^e₀ · x₁^e₁ · …
+    pub fn monomial(exponents: &[usize], c: S) -> Self {
+        let mut p = MultiPolynomial::default();
+        p.add_term(exponents, c);
+        p
+    }
+
+    /// Returns the constant polynomial c.
+    pub fn constant(c: S) -> Self {
+        MultiPolynomial::monomial(&[], c)
+    }
+
+    /// Adds c · x₀^e₀ · x₁^e₁ · … to the polynomial.
+    pub fn add_term(&mut self, exponents: &[usize], c: S) {
+        let exponents = trim_exponents(exponents);
+        let sum = self.coeff(exponents) + c;
+        if sum.is_zero() {
+            self.terms.remove(exponents);
+        } else {
+            self.terms.insert(exponents.to_vec(), sum);
+        }
+    }
+
+    /// Returns the coefficient of x₀^e₀ · x₁^e₁ · …
+    pub fn coeff(&self, exponents: &[usize]) -> S {
+        self.terms
+            .get(trim_exponents(exponents))
+            .copied()
+            .unwrap_or_else(S::zero)
+    }
+
+    /// Returns an iterator over the exponents and coefficients of the nonzero terms.
+    pub fn terms(&self) -> impl Iterator<Item = (&[usize], S)> {
+        self.terms.iter().map(|(e, c)| (e.as_slice(), *c))
+    }
+
+    /// Returns the number of variables, i.e. one more than the index of the last variable that
+    /// occurs in a term.
+    pub fn num_vars(&self) -> usize {
+        self.terms.keys().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the total degree. The zero polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        self.terms.keys().map(|e| e.iter().sum()).max().unwrap_or(0)
+    }
+
+    /// Returns whether this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<S> MultiPolynomial<S>
+where
+    S: Copy + Zero + One,
+{
+    /// Returns the polynomial consisting of the variable with the given index.
+    pub fn variable(index: usize) -> Self {
+        let mut exponents = vec![0; index + 1];
+        exponents[index] = 1;
+        MultiPolynomial::monomial(&exponents, S::one())
+    }
+}
+
+impl<S> MultiPolynomial<S>
+where
+    S: Float,
+{
+    /// Evaluates the polynomial with `values[i]` for the i-th variable.
+    ///
+    /// # Panics
+    /// - if there are fewer values than variables
+    pub fn eval(&self, values: &[S]) -> S {
+        assert!(values.len() >= self.num_vars(), "too few values");
+        self.terms.iter().fold(S::zero(), |acc, (exponents, c)| {
+            let term = exponents
+                .iter()
+                .zip(values)
+                .fold(*c, |term, (e, x)| (0..*e).fold(term, |term, _| term * *x));
+            acc + term
+        })
+    }
+
+    /// Returns the partial derivative with respect to the variable with the given index.
+    ///
+    /// # Panics
+    /// - if an exponent cannot be represented by the scalar type S
+    pub fn partial_derivative(&self, index: usize) -> Self {
+        let mut out = MultiPolynomial::default();
+        for (exponents, c) in &self.terms {
+            match exponents.get(index) {
+                Some(&e) if e > 0 => {
+                    let mut exponents = exponents.clone();
+                    exponents[index] = e - 1;
+                    out.add_term(&exponents, *c * S::from(e).unwrap());
+                }
+                _ => {}
+            }
+        }
+        out
+    }
+
+    /// Substitutes `polys[i]` for the i-th variable, which yields a polynomial in one variable.
+    ///
+    /// # Panics
+    /// - if there are fewer polynomials than variables
+    pub fn subst(&self, polys: &[Polynomial<S>]) -> Polynomial<S> {
+        assert!(polys.len() >= self.num_vars(), "too few polynomials");
+        // powers[i][e] is polys[i]^e
+        let mut powers: Vec<Vec<Polynomial<S>>> =
+            vec![vec![Polynomial::from(vec![S::one()])]; polys.len()];
+        let mut out = Polynomial::default();
+        for (exponents, c) in &self.terms {
+            let mut term = Polynomial::from(vec![*c]);
+            for (i, e) in exponents.iter().enumerate() {
+                while powers[i].len() <= *e {
+                    let next = powers[i].last().unwrap().clone() * polys[i].clone();
+                    powers[i].push(next);
+                }
+                term = term * powers[i][*e].clone();
+            }
+            out = out + term;
+        }
+        out
+    }
+}
+
+impl<S> ops::Add for MultiPolynomial<S>
+where
+    S: Copy + Zero,
+{
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        for (exponents, c) in rhs.terms {
+            self.add_term(&exponents, c);
+        }
+        self
+    }
+}
+
+impl<S> ops::Neg for MultiPolynomial<S>
+where
+    S: Copy + ops::Neg<Output = S>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        let terms = self.terms.into_iter().map(|(e, c)| (e, -c)).collect();
+        MultiPolynomial { terms }
+    }
+}
+
+impl<S> ops::Sub for MultiPolynomial<S>
+where
+    S: Copy + Zero + ops::Neg<Output = S>,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<S> ops::Mul<S> for MultiPolynomial<S>
+where
+    S: Copy + Zero + ops::Mul<S, Output = S>,
+{
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self {
+        let mut out = MultiPolynomial::default();
+        for (exponents, c) in self.terms {
+            out.add_term(&exponents, c * rhs);
+        }
+        out
+    }
+}
+
+impl<S> ops::Mul for MultiPolynomial<S>
+where
+    S: Copy + Zero + ops::Mul<S, Output = S>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let mut out = MultiPolynomial::default();
+        for (e1, c1) in &self.terms {
+            for (e2, c2) in &rhs.terms {
+                let mut exponents = vec![0; e1.len().max(e2.len())];
+                for (i, e) in e1.iter().enumerate() {
+                    exponents[i] += e;
+                }
+                for (i, e) in e2.iter().enumerate() {
+                    exponents[i] += e;
+                }
+                out.add_term(&exponents, *c1 * *c2);
+            }
+        }
+        out
+    }
+}
+
+impl<S> From<Polynomial<S>> for MultiPolynomial<S>
+where
+    S: Copy + Zero,
+{
+    /// Converts a polynomial in one variable, which becomes the variable with index 0.
+    fn from(p: Polynomial<S>) -> Self {
+        let mut out = MultiPolynomial::default();
+        for (i, c) in p.coeffs.into_iter().enumerate() {
+            out.add_term(&[i], c);
+        }
+        out
+    }
+}
+
+impl<S> From<Poly1x2d<S>> for MultiPolynomial<S>
+where
+    S: Copy + Zero,
+{
+    /// Converts a linear polynomial, with x and y as the variables with indices 0 and 1.
+    fn from(p: Poly1x2d<S>) -> Self {
+        let mut out = MultiPolynomial::constant(p.k);
+        out.add_term(&[1, 0], p.x);
+        out.add_term(&[0, 1], p.y);
+        out
+    }
+}
+
+impl<S> From<Poly2x2d<S>> for MultiPolynomial<S>
+where
+    S: Copy + Zero,
+{
+    /// Converts a quadratic polynomial, with x and y as the variables with indices 0 and 1.
+    fn from(p: Poly2x2d<S>) -> Self {
+        let mut out = MultiPolynomial::from(Poly1x2d {
+            k: p.k,
+            x: p.x,
+            y: p.y,
+        });
+        out.add_term(&[1, 1], p.xy);
+        out.add_term(&[2, 0], p.xx);
+        out.add_term(&[0, 2], p.yy);
+        out
+    }
+}
+
+impl<S> From<Poly3x2d<S>> for MultiPolynomial<S>
+where
+    S: Copy + Zero,
+{
+    /// Converts a cubic polynomial, with x and y as the variables with indices 0 and 1.
+    fn from(p: Poly3x2d<S>) -> Self {
+        let mut out = MultiPolynomial::from(Poly2x2d {
+            k: p.k,
+            x: p.x,
+            y: p.y,
+            xy: p.xy,
+            xx: p.xx,
+            yy: p.yy,
+        });
+        out.add_term(&[2, 1], p.xxy);
+        out.add_term(&[1, 2], p.xyy);
+        out.add_term(&[3, 0], p.xxx);
+        out.add_term(&[0, 3], p.yyy);
+        out
+    }
+}
+
+impl<S> From<PolyNx2d<S>> for MultiPolynomial<S>
+where
+    S: Copy + Zero,
+{
+    /// Converts a dense polynomial, with x and y as the variables with indices 0 and 1.
+    fn from(p: PolyNx2d<S>) -> Self {
+        let mut out = MultiPolynomial::default();
+        for (i, row) in p.coeffs.into_iter().enumerate() {
+            for (j, c) in row.into_iter().enumerate() {
+                out.add_term(&[i, j], c);
+            }
+        }
+        out
+    }
+}
+
+impl<S> TryFrom<MultiPolynomial<S>> for PolyNx2d<S>
+where
+    S: Copy + Zero,
+{
+    type Error = ConversionError;
+
+    /// Converts a polynomial in at most two variables, which become x and y.
+    fn try_from(p: MultiPolynomial<S>) -> Result<Self, ConversionError> {
+        if p.num_vars() > 2 {
+            return Err(ConversionError);
+        }
+        let mut coeffs: Vec<Vec<S>> = Vec::new();
+        for (exponents, c) in p.terms {
+            let i = exponents.first().copied().unwrap_or(0);
+            let j = exponents.get(1).copied().unwrap_or(0);
+            if coeffs.len() <= i {
+                coeffs.resize(i + 1, Vec::new());
+            }
+            if coeffs[i].len() <= j {
+                coeffs[i].resize(j + 1, S::zero());
+            }
+            coeffs[i][j] = c;
+        }
+        Ok(PolyNx2d { coeffs })
+    }
+}
+
+/// Checks that a polynomial has at most two variables and at most the given degree.
+fn check_bivariate<S>(p: &MultiPolynomial<S>, degree: usize) -> Result<(), ConversionError>
+where
+    S: Copy + Zero,
+{
+    if p.num_vars() > 2 || p.degree() > degree {
+        return Err(ConversionError);
+    }
+    Ok(())
+}
+
+impl<S> TryFrom<MultiPolynomial<S>> for Poly1x2d<S>
+where
+    S: Copy + Zero,
+{
+    type Error = ConversionError;
+
+    /// Converts a polynomial of degree at most 1 in at most two variables, which become x and y.
+    fn try_from(p: MultiPolynomial<S>) -> Result<Self, ConversionError> {
+        check_bivariate(&p, 1)?;
+        Ok(Poly1x2d {
+            k: p.coeff(&[0, 0]),
+            x: p.coeff(&[1, 0]),
+            y: p.coeff(&[0, 1]),
+        })
+    }
+}
+
+impl<S> TryFrom<MultiPolynomial<S>> for Poly2x2d<S>
+where
+    S: Copy + Zero,
+{
+    type Error = ConversionError;
+
+    /// Converts a polynomial of degree at most 2 in at most two variables, which become x and y.
+    fn try_from(p: MultiPolynomial<S>) -> Result<Self, ConversionError> {
+        check_bivariate(&p, 2)?;
+        Ok(Poly2x2d {
+            k: p.coeff(&[0, 0]),
+            x: p.coeff(&[1, 0]),
+            y: p.coeff(&[0, 1]),
+            xy: p.coeff(&[1, 1]),
+            xx: p.coeff(&[2, 0]),
+            yy: p.coeff(&[0, 2]),
+        })
+    }
+}
+
+impl<S> TryFrom<MultiPolynomial<S>> for Poly3x2d<S>
+where
+    S: Copy + Zero,
+{
+    type Error = ConversionError;
+
+    /// Converts a polynomial of degree at most 3 in at most two variables, which become x and y.
+    fn try_from(p: MultiPolynomial<S>) -> Result<Self, ConversionError> {
+        check_bivariate(&p, 3)?;
+        Ok(Poly3x2d {
+            k: p.coeff(&[0, 0]),
+            x: p.coeff(&[1, 0]),
+            y: p.coeff(&[0, 1]),
+            xy: p.coeff(&[1, 1]),
+            xx: p.coeff(&[2, 0]),
+            yy: p.coeff(&[0, 2]),
+            xxy: p.coeff(&[2, 1]),
+            xyy: p.coeff(&[1, 2]),
+            xxx: p.coeff(&[3, 0]),
+            yyy: p.coeff(&[0, 3]),
+        })
+    }
+}
+
+#[test]
+fn test_multi_polynomial() {
+    use cgmath::assert_abs_diff_eq;
+
+    let x = MultiPolynomial::<f64>::variable(0);
+    let y = MultiPolynomial::variable(1);
+    let z = MultiPolynomial::variable(2);
+    let one = MultiPolynomial::constant(1.);
+
+    // p = x²y - 3yz + 1, q = x + z
+    let p = x.clone() * x.clone() * y.clone() - y.clone() * z.clone() * 3. + one.clone();
+    let q = x.clone() + z.clone();
+    assert_eq!(p.num_vars(), 3);
+    assert_eq!(p.degree(), 3);
+    assert_eq!(p.coeff(&[2, 1]), 1.);
+    assert_eq!(p.coeff(&[2, 1, 0, 0]), 1.);
+    assert_eq!(p.coeff(&[0, 1, 1]), -3.);
+    assert_eq!(p.coeff(&[1]), 0.);
+    assert_eq!(p.terms().count(), 3);
+    assert!((p.clone() - p.clone()).is_zero());
+
+    let f = |v: [f64; 3]| v[0] * v[0] * v[1] - 3. * v[1] * v[2] + 1.;
+    let g = |v: [f64; 3]| v[0] + v[2];
+    for v in [[0., 0., 0.], [1., -2., 0.5], [-1.5, 3., 2.]].iter() {
+        assert_abs_diff_eq!(p.eval(v), f(*v), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            (p.clone() + q.clone()).eval(v),
+            f(*v) + g(*v),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            (p.clone() * q.clone()).eval(v),
+            f(*v) * g(*v),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!((-q.clone()).eval(v), -g(*v), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            p.partial_derivative(0).eval(v),
+            2. * v[0] * v[1],
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(p.partial_derivative(2).eval(v), -3. * v[1], epsilon = 1e-12);
+    }
+    assert!(p.partial_derivative(3).is_zero());
+
+    // substitute (t, t - 1, 2t²)
+    let polys = [
+        Polynomial::from(vec![0., 1.]),
+        Polynomial::from(vec![-1., 1.]),
+        Polynomial::from(vec![0., 0., 2.]),
+    ];
+    let subst = p.subst(&polys);
+    assert_eq!(subst.degree(), 3);
+    for &t in [-1., 0.25, 2.].iter() {
+        let v = [t, t - 1., 2. * t * t];
+        assert_abs_diff_eq!(subst.eval(t), f(v), epsilon = 1e-12);
+    }
+
+    // conversions to and from the fixed structs
+    let cubic = Poly3x2d {
+        k: 1.,
+        x: 2.,
+        y: -1.,
+        xy: 0.5,
+        xx: 0.,
+        yy: 1.,
+        xxy: 3.,
+        xyy: 0.,
+        xxx: 1.,
+        yyy: -2.,
+    };
+    let multi = MultiPolynomial::from(cubic);
+    assert_eq!(multi.coeff(&[2, 1]), 3.);
+    assert_eq!(multi.coeff(&[0, 3]), -2.);
+    assert_eq!(multi.coeff(&[2]), 0.);
+    let square = multi.clone() * multi.clone();
+    assert_eq!(square.degree(), 6);
+    for &(x, y) in [(0.5, -1.), (2., 3.)].iter() {
+        assert_abs_diff_eq!(multi.eval(&[x, y]), cubic.eval(x, y), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            square.eval(&[x, y]),
+            cubic.eval(x, y) * cubic.eval(x, y),
+            epsilon = 1e-9
+        );
+    }
+    let back = Poly3x2d::try_from(multi.clone()).unwrap();
+    assert_eq!(MultiPolynomial::from(back), multi);
+    assert_eq!(
+        Poly2x2d::try_from(multi.clone()).err(),
+        Some(ConversionError)
+    );
+    let dense = PolyNx2d::try_from(square.clone()).unwrap();
+    assert_eq!(dense.degree(), 6);
+    assert_abs_diff_eq!(dense.eval(2., 3.), square.eval(&[2., 3.]), epsilon = 1e-9);
+    assert_eq!(MultiPolynomial::from(dense), square);
+    assert_eq!(PolyNx2d::try_from(z.clone()).err(), Some(ConversionError));
+    assert!(Poly3x2d::try_from(square).is_err());
+    assert!(Poly3x2d::try_from(z).is_err());
+
+    let line = Poly1x2d {
+        k: 1.,
+        x: -2.,
+        y: 3.,
+    };
+    let back = Poly1x2d::try_from(MultiPolynomial::from(line)).unwrap();
+    assert_eq!((back.k, back.x, back.y), (1., -2., 3.));
+    let product =
+        Poly2x2d::try_from(MultiPolynomial::from(line) * MultiPolynomial::from(line)).unwrap();
+    assert_abs_diff_eq!(product.eval(0.5, 2.), (line * line).eval(0.5, 2.));
+
+    let uni = MultiPolynomial::from(Polynomial::from(vec![1., 0., 2.]));
+    assert_eq!(uni, one + x.clone() * x * 2.);
+
+    // substitution agrees with the fixed structs
+    let quadratic = Poly2x2d {
+        k: 2.,
+        x: -1.,
+        y: 0.5,
+        xy: 3.,
+        xx: -2.,
+        yy: 1.,
+    };
+    let (px, py) = (vec![1., 2., -1.], vec![0.5, -2., 3.]);
+    let polys = [Polynomial::from(px.clone()), Polynomial::from(py.clone())];
+    let subst = MultiPolynomial::from(quadratic).subst(&polys);
+    assert_eq!(subst, Polynomial::from(quadratic.subst(&px, &py)));
+    let subst = MultiPolynomial::from(cubic).subst(&polys);
+    let subst_ref = Polynomial::from(cubic.subst(&px, &py));
+    assert_eq!(subst.coeffs.len(), subst_ref.coeffs.len());
+    for (s, r) in subst.coeffs.iter().zip(subst_ref.coeffs.iter()) {
+        assert_abs_diff_eq!(s, r, epsilon = 1e-10);
+    }
+}
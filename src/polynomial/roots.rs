@@ -0,0 +1,542 @@
+use cgmath::num_traits::Float;
+
+/// Evaluates a polynomial (coefficients lowest degree first) using Horner’s method.
+pub(crate) fn eval_slice<S>(coeffs: &[S], x: S) -> S
+where
+    S: Float,
+{
+    coeffs.iter().rev().fold(S::zero(), |acc, c| acc * x + *c)
+}
+
+/// Returns an upper bound for the magnitude of the terms of a polynomial at x, which is used to
+/// estimate rounding error.
+fn eval_bound_slice<S>(coeffs: &[S], x: S) -> S
+where
+    S: Float,
+{
+    let x = x.abs();
+    coeffs
+        .iter()
+        .rev()
+        .fold(S::zero(), |acc, c| acc * x + c.abs())
+}
+
+/// Finds the real roots of a polynomial (coefficients lowest degree first) in the interval
+/// [min, max], in ascending order.
+///
+/// Vanishing leading coefficients are ignored. The zero polynomial has no roots.
+///
+/// # Details
+/// Roots are found natively in S, without conversion to another scalar type, by recursively
+/// finding the extrema of the polynomial (the roots of its derivative). Between adjacent extrema,
+/// the polynomial is monotonic and has at most one root, which is found using the Illinois
+/// variant of regula falsi. Extrema that touch zero within rounding error are roots of even
+/// multiplicity and are included once.
+pub fn real_roots_in<S>(coeffs: &[S], min: S, max: S) -> Vec<S>
+where
+    S: Float,
+{
+    let scale = coeffs.iter().fold(S::zero(), |acc, c| acc.max(c.abs()));
+    match coeffs.iter().rposition(|c| c.abs() > scale * S::epsilon()) {
+        Some(degree) => real_roots_impl(&coeffs[..=degree], min, max),
+        None => Vec::new(),
+    }
+}
+
+fn real_roots_impl<S>(coeffs: &[S], min: S, max: S) -> Vec<S>
+where
+    S: Float,
+{
+    let zero = S::zero();
+    if coeffs.len() <= 1 {
+        return Vec::new();
+    }
+    if coeffs.len() == 2 {
+        let root = -coeffs[0] / coeffs[1];
+        return if root >= min && root <= max {
+            vec![root]
+        } else {
+            Vec::new()
+        };
+    }
+
+    let derivative = derive_slice(coeffs);
+
+    let mut points = vec![min];
+    for x in real_roots_impl(&derivative, min, max) {
+        if x > *points.last().unwrap() && x < max {
+            points.push(x);
+        }
+    }
+    points.push(max);
+    let values: Vec<S> = points.iter().map(|x| eval_slice(coeffs, *x)).collect();
+
+    let crosses = |i: usize| {
+        i + 1 < points.len()
+            && ((values[i] < zero && values[i + 1] > zero)
+                || (values[i] > zero && values[i + 1] < zero))
+    };
+
+    let mut roots = Vec::new();
+    for i in 0..points.len() {
+        let touches_zero = values[i] == zero
+            || (values[i].abs() <= eval_bound_slice(coeffs, points[i]) * S::epsilon().sqrt()
+                && !(i > 0 && crosses(i - 1))
+                && !crosses(i));
+        if touches_zero {
+            roots.push(points[i]);
+        }
+        if crosses(i) {
+            roots.push(solve_monotonic(
+                coeffs,
+                points[i],
+                points[i + 1],
+                values[i],
+                values[i + 1],
+            ));
+        }
+    }
+    roots
+}
+
+/// Finds the root of a polynomial in [a, b], where the values fa and fb at the bounds have
+/// opposite signs and there is exactly one root in between.
+fn solve_monotonic<S>(coeffs: &[S], mut a: S, mut b: S, mut fa: S, mut fb: S) -> S
+where
+    S: Float,
+{
+    let two = S::one() + S::one();
+    let mut side = 0;
+    let mut x = a;
+    for _ in 0..128 {
+        x = (a * fb - b * fa) / (fb - fa);
+        if !(x > a && x < b) {
+            x = (a + b) / two;
+        }
+        let fx = eval_slice(coeffs, x);
+        if fx == S::zero() {
+            break;
+        }
+        if (fa < S::zero()) == (fx < S::zero()) {
+            a = x;
+            fa = fx;
+            if side == 1 {
+                fb = fb / two;
+            }
+            side = 1;
+        } else {
+            b = x;
+            fb = fx;
+            if side == -1 {
+                fa = fa / two;
+            }
+            side = -1;
+        }
+        if b - a <= S::epsilon() * a.abs().max(b.abs()) {
+            break;
+        }
+    }
+    x
+}
+
+/// A real root of a polynomial.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Root<S> {
+    /// Location of the root.
+    pub value: S,
+    /// Multiplicity of the root, i.e. the number of derivatives of the polynomial (including the
+    /// polynomial itself) that vanish at the root.
+    pub multiplicity: usize,
+}
+
+/// Returns the derivative of a polynomial (coefficients lowest degree first).
+fn derive_slice<S>(coeffs: &[S]) -> Vec<S>
+where
+    S: Float,
+{
+    let mut n = S::zero();
+    coeffs
+        .iter()
+        .skip(1)
+        .map(|c| {
+            n = n + S::one();
+            *c * n
+        })
+        .collect()
+}
+
+/// Finds the real roots of a polynomial (coefficients lowest degree first) in the interval
+/// [min, max] with their multiplicities, in ascending order.
+///
+/// Vanishing leading coefficients are ignored. The zero polynomial has no roots.
+///
+/// # Details
+/// Roots are found using [`real_roots_in`]. A root of multiplicity m is a simple root of the
+/// (m - 1)th derivative, which is therefore located first; the root has multiplicity m if the
+/// lower derivatives vanish there within rounding error. Roots of multiplicity m are only
+/// determined up to about the mth root of the machine epsilon, so roots that are closer together
+/// are reported as a single multiple root.
+pub fn real_roots_with_multiplicity<S>(coeffs: &[S], min: S, max: S) -> Vec<Root<S>>
+where
+    S: Float,
+{
+    let scale = coeffs.iter().fold(S::zero(), |acc, c| acc.max(c.abs()));
+    let coeffs = match coeffs.iter().rposition(|c| c.abs() > scale * S::epsilon()) {
+        Some(degree) => &coeffs[..=degree],
+        None => return Vec::new(),
+    };
+
+    // derivatives[k] is the kth derivative
+    let mut derivatives = vec![coeffs.to_vec()];
+    while derivatives.last().unwrap().len() > 2 {
+        let next = derive_slice(derivatives.last().unwrap());
+        derivatives.push(next);
+    }
+    let derivative_roots: Vec<Vec<S>> = derivatives
+        .iter()
+        .map(|d| real_roots_impl(d, min, max))
+        .collect();
+
+    let tolerance = S::epsilon() * S::from(4 * coeffs.len()).unwrap();
+    let vanishes = |k: usize, x: S| {
+        let d = &derivatives[k];
+        eval_slice(d, x).abs() <= eval_bound_slice(d, x) * tolerance
+    };
+
+    let mut roots: Vec<Root<S>> = Vec::new();
+    for root in &derivative_roots[0] {
+        // the closest root of the highest derivative at which all lower derivatives vanish
+        let mut value = *root;
+        let mut multiplicity = 1;
+        for (k, derivative_roots) in derivative_roots.iter().enumerate().skip(1) {
+            let closest = derivative_roots
+                .iter()
+                .copied()
+                .fold(None, |closest: Option<S>, x| match closest {
+                    Some(c) if (c - *root).abs() <= (x - *root).abs() => Some(c),
+                    _ => Some(x),
+                });
+            match closest {
+                Some(x) if (0..k).all(|j| vanishes(j, x)) => {
+                    value = x;
+                    multiplicity = k + 1;
+                }
+                _ => break,
+            }
+        }
+
+        // several roots of the polynomial may be found near a multiple root
+        if !roots.last().is_some_and(|last| last.value == value) {
+            roots.push(Root {
+                value,
+                multiplicity,
+            });
+        }
+    }
+    roots
+}
+
+/// Converts a small integer constant to S.
+fn constant<S>(n: i32) -> S
+where
+    S: Float,
+{
+    S::from(n).unwrap()
+}
+
+/// Refines a root of a polynomial (coefficients lowest degree first) using Newton’s method.
+fn polish<S>(coeffs: &[S], mut x: S) -> S
+where
+    S: Float,
+{
+    let derivative = derive_slice(coeffs);
+    for _ in 0..4 {
+        let fx = eval_slice(coeffs, x);
+        let step = fx / eval_slice(&derivative, x);
+        let next = x - step;
+        if !next.is_finite() || eval_slice(coeffs, next).abs() >= fx.abs() {
+            break;
+        }
+        x = next;
+    }
+    x
+}
+
+/// Sorts roots and removes duplicates.
+fn sorted<S>(mut roots: Vec<S>) -> Vec<S>
+where
+    S: Float,
+{
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots.dedup();
+    roots
+}
+
+/// Finds the real roots of c₀ + c₁x + c₂x² (coefficients lowest degree first), in ascending
+/// order.
+///
+/// A vanishing leading coefficient reduces the degree. Double roots, including those within
+/// rounding error, are returned once.
+pub fn real_roots_quadratic<S>(coeffs: [S; 3]) -> Vec<S>
+where
+    S: Float,
+{
+    let [c, b, a] = coeffs;
+    if a == S::zero() {
+        return if b == S::zero() {
+            Vec::new()
+        } else {
+            vec![-c / b]
+        };
+    }
+    let four_ac = constant::<S>(4) * a * c;
+    let discriminant = b * b - four_ac;
+    if discriminant.abs() <= constant::<S>(4) * S::epsilon() * (b * b + four_ac.abs()) {
+        return vec![-b / (constant::<S>(2) * a)];
+    }
+    if discriminant < S::zero() {
+        return Vec::new();
+    }
+    // avoids cancellation between b and the square root
+    let q = -(b + b.signum() * discriminant.sqrt()) / constant(2);
+    sorted(vec![q / a, c / q])
+}
+
+/// Finds the real roots of c₀ + c₁x + c₂x² + c₃x³ (coefficients lowest degree first), in
+/// ascending order.
+///
+/// A vanishing leading coefficient reduces the degree. Multiple roots are returned once.
+///
+/// # Details
+/// The cubic is reduced to the depressed cubic t³ + pt + q, which is solved using Cardano’s
+/// formula if it has a single real root, and using the trigonometric method otherwise. Roots are
+/// refined with a few Newton steps.
+pub fn real_roots_cubic<S>(coeffs: [S; 4]) -> Vec<S>
+where
+    S: Float,
+{
+    let [d, c, b, a] = coeffs;
+    if a == S::zero() {
+        return real_roots_quadratic([d, c, b]);
+    }
+    let (b, c, d) = (b / a, c / a, d / a);
+    let (two, three) = (constant::<S>(2), constant::<S>(3));
+
+    let p = c - b * b / three;
+    let q = two * b * b * b / constant(27) - b * c / three + d;
+    let discriminant = (q / two).powi(2) + (p / three).powi(3);
+    let scale = (q / two).powi(2) + (p / three).powi(3).abs();
+
+    let depressed = if discriminant.abs() <= constant::<S>(8) * S::epsilon() * scale {
+        if p == S::zero() || q == S::zero() {
+            // triple root
+            vec![S::zero()]
+        } else {
+            vec![three * q / p, -three * q / (two * p)]
+        }
+    } else if discriminant > S::zero() {
+        let u = (-q / two - q.signum() * discriminant.sqrt()).cbrt();
+        vec![u - p / (three * u)]
+    } else {
+        let r = two * (-p / three).sqrt();
+        let cos = (three * q / (two * p) * (-three / p).sqrt())
+            .max(-S::one())
+            .min(S::one());
+        let phi = cos.acos() / three;
+        let pi = (-S::one()).acos();
+        (0..3)
+            .map(|k| r * (phi - two * pi * constant(k) / three).cos())
+            .collect()
+    };
+
+    let monic = [d, c, b, S::one()];
+    sorted(
+        depressed
+            .into_iter()
+            .map(|t| polish(&monic, t - b / three))
+            .collect(),
+    )
+}
+
+/// Finds the real roots of c₀ + c₁x + c₂x² + c₃x³ + c₄x⁴ (coefficients lowest degree first), in
+/// ascending order.
+///
+/// A vanishing leading coefficient reduces the degree. Multiple roots are returned once.
+///
+/// # Details
+/// The quartic is reduced to the depressed quartic y⁴ + py² + qy + r, which is solved using
+/// Ferrari’s method: for a positive root m of the resolvent cubic
+/// 8m³ + 8pm² + (2p² - 8r)m - q², it factors into two quadratics. Roots are refined with a few
+/// Newton steps.
+pub fn real_roots_quartic<S>(coeffs: [S; 5]) -> Vec<S>
+where
+    S: Float,
+{
+    let [e, d, c, b, a] = coeffs;
+    if a == S::zero() {
+        return real_roots_cubic([e, d, c, b]);
+    }
+    let (b, c, d, e) = (b / a, c / a, d / a, e / a);
+    let (two, four, eight) = (constant::<S>(2), constant::<S>(4), constant::<S>(8));
+
+    let p = c - constant::<S>(3) * b * b / eight;
+    let q = d - b * c / two + b * b * b / eight;
+    let r = e - b * d / four + b * b * c / constant(16)
+        - constant::<S>(3) * b * b * b * b / constant(256);
+
+    let resolvent = [-q * q, two * p * p - eight * r, eight * p, eight];
+    let m = real_roots_cubic(resolvent)
+        .into_iter()
+        .fold(S::zero(), |m, x| m.max(x));
+
+    let depressed = if q == S::zero() || m <= S::zero() {
+        // biquadratic y⁴ + py² + r
+        real_roots_quadratic([r, p, S::one()])
+            .into_iter()
+            .filter(|z| *z >= S::zero())
+            .flat_map(|z| vec![z.sqrt(), -z.sqrt()])
+            .collect::<Vec<S>>()
+    } else {
+        let s = (two * m).sqrt();
+        let mut roots = real_roots_quadratic([p / two + m + q / (two * s), -s, S::one()]);
+        roots.extend(real_roots_quadratic([
+            p / two + m - q / (two * s),
+            s,
+            S::one(),
+        ]));
+        roots
+    };
+
+    let monic = [e, d, c, b, S::one()];
+    sorted(
+        depressed
+            .into_iter()
+            .map(|y| polish(&monic, y - b / four))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_real_roots_in() {
+    use cgmath::assert_abs_diff_eq;
+
+    // (x - 0.25)(x - 0.5)(x - 2) = x³ - 2.75x² + 1.625x - 0.25
+    let roots = real_roots_in(&[-0.25, 1.625, -2.75, 1.], 0., 1.);
+    assert_eq!(roots.len(), 2);
+    assert_abs_diff_eq!(roots[0], 0.25, epsilon = 1e-12);
+    assert_abs_diff_eq!(roots[1], 0.5, epsilon = 1e-12);
+
+    // same, multiplied by (x - 0.75)(x + 1) with a vanishing leading coefficient
+    let p = [0.1875, -1.28125, 2.21875, 0.1875, -2.5, 1., 0.];
+    let roots = real_roots_in(&p, 0., 1.);
+    assert_eq!(roots.len(), 3);
+    for (root, expected) in roots.iter().zip([0.25, 0.5, 0.75].iter()) {
+        assert_abs_diff_eq!(root, expected, epsilon = 1e-9);
+    }
+
+    // (x - 0.5)² (x - 0.25) has a double root
+    let roots = real_roots_in(&[-0.0625, 0.5, -1.25, 1.], 0., 1.);
+    assert_eq!(roots.len(), 2);
+    assert_abs_diff_eq!(roots[0], 0.25, epsilon = 1e-12);
+    assert_abs_diff_eq!(roots[1], 0.5, epsilon = 1e-6);
+
+    // also in f32
+    let roots = real_roots_in(&[-0.25_f32, 1.625, -2.75, 1.], 0., 1.);
+    assert_eq!(roots.len(), 2);
+    assert_abs_diff_eq!(roots[0], 0.25, epsilon = 1e-6);
+    assert_abs_diff_eq!(roots[1], 0.5, epsilon = 1e-6);
+
+    assert!(real_roots_in(&[1., 0., 1.], -10., 10.).is_empty());
+    assert!(real_roots_in(&[0., 0.], -10., 10.).is_empty());
+}
+
+#[test]
+fn test_real_roots_with_multiplicity() {
+    use cgmath::assert_abs_diff_eq;
+
+    let mul = |a: &[f64], b: &[f64]| {
+        let mut out = vec![0.; a.len() + b.len() - 1];
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                out[i + j] += a * b;
+            }
+        }
+        out
+    };
+
+    // (x - 0.2)(x - 0.5)³(x - 0.9)²(x - 2)
+    let mut p = vec![-0.2, 1.];
+    for root in [0.5, 0.5, 0.5, 0.9, 0.9, 2.].iter() {
+        p = mul(&p, &[-root, 1.]);
+    }
+    let roots = real_roots_with_multiplicity(&p, 0., 1.);
+    assert_eq!(roots.len(), 3);
+    for (root, (value, multiplicity)) in roots.iter().zip([(0.2, 1), (0.5, 3), (0.9, 2)].iter()) {
+        assert_abs_diff_eq!(root.value, value, epsilon = 1e-5);
+        assert_eq!(root.multiplicity, *multiplicity);
+    }
+
+    // close but distinct roots
+    let p = mul(&[-0.5, 1.], &[-0.5001, 1.]);
+    let roots = real_roots_with_multiplicity(&p, 0., 1.);
+    assert_eq!(roots.len(), 2);
+    assert!(roots.iter().all(|root| root.multiplicity == 1));
+
+    assert!(real_roots_with_multiplicity(&[0., 0., 0.], 0., 1.).is_empty());
+}
+
+#[test]
+fn test_real_roots_closed_form() {
+    use cgmath::assert_abs_diff_eq;
+
+    let check = |roots: Vec<f64>, expected: &[f64]| {
+        assert_eq!(roots.len(), expected.len(), "{:?} != {:?}", roots, expected);
+        for (root, expected) in roots.iter().zip(expected.iter()) {
+            assert_abs_diff_eq!(root, expected, epsilon = 1e-9);
+        }
+    };
+
+    check(real_roots_quadratic([2., -3., 1.]), &[1., 2.]);
+    check(real_roots_quadratic([1., -2., 1.]), &[1.]);
+    check(real_roots_quadratic([1., 0., 1.]), &[]);
+    check(real_roots_quadratic([-3., 2., 0.]), &[1.5]);
+    // roots of very different magnitude
+    check(real_roots_quadratic([1., -1e8, 1.]), &[1e-8, 1e8]);
+
+    // (x - 1)(x - 2)(x - 3)
+    check(real_roots_cubic([-6., 11., -6., 1.]), &[1., 2., 3.]);
+    check(real_roots_cubic([-12., 22., -12., 2.]), &[1., 2., 3.]);
+    // (x - 1)²(x + 2)
+    check(real_roots_cubic([2., -3., 0., 1.]), &[-2., 1.]);
+    // (x - 1)³
+    check(real_roots_cubic([-1., 3., -3., 1.]), &[1.]);
+    check(real_roots_cubic([-1., 0., 0., 1.]), &[1.]);
+    check(real_roots_cubic([1., 1., 0., 1.]), &[-0.6823278038280193]);
+    check(real_roots_cubic([2., -3., 1., 0.]), &[1., 2.]);
+
+    // (x - 1)(x - 2)(x + 1)(x + 3)
+    check(
+        real_roots_quartic([6., -1., -7., 1., 1.]),
+        &[-3., -1., 1., 2.],
+    );
+    check(
+        real_roots_quartic([4., 0., -5., 0., 1.]),
+        &[-2., -1., 1., 2.],
+    );
+    check(real_roots_quartic([1., 0., 0., 0., 1.]), &[]);
+    // (x - 1)²(x² + 1)
+    check(real_roots_quartic([1., -2., 2., -2., 1.]), &[1.]);
+    check(real_roots_quartic([-6., 11., -6., 1., 0.]), &[1., 2., 3.]);
+
+    let p = super::Poly3 {
+        k: -6.,
+        x: 11.,
+        xx: -6.,
+        xxx: 1.,
+    };
+    check(p.real_roots(), &[1., 2., 3.]);
+    // also in f32
+    let roots = real_roots_cubic([-6_f32, 11., -6., 1.]);
+    assert_eq!(roots.len(), 3);
+    assert_abs_diff_eq!(roots[2], 3., epsilon = 1e-4);
+}
@@ -0,0 +1,321 @@
+use super::{real_roots_in, real_roots_with_multiplicity, Poly3, Root};
+use cgmath::num_traits::Float;
+use cgmath::Zero;
+use std::ops;
+
+/// Polynomial of arbitrary degree in one variable
+///
+/// `coeffs[i]` is the coefficient of xⁱ. Vanishing leading coefficients are allowed and ignored
+/// in comparisons.
+#[derive(Debug, Clone)]
+pub struct Polynomial<S> {
+    pub coeffs: Vec<S>,
+}
+
+impl<S> Default for Polynomial<S> {
+    fn default() -> Self {
+        Polynomial { coeffs: Vec::new() }
+    }
+}
+
+impl<S> From<Vec<S>> for Polynomial<S> {
+    fn from(coeffs: Vec<S>) -> Self {
+        Polynomial { coeffs }
+    }
+}
+
+impl<S> From<Poly3<S>> for Polynomial<S> {
+    fn from(p: Poly3<S>) -> Self {
+        Polynomial {
+            coeffs: vec![p.k, p.x, p.xx, p.xxx],
+        }
+    }
+}
+
+impl<S> Polynomial<S>
+where
+    S: Copy + Zero,
+{
+    /// Returns the coefficients without vanishing leading coefficients.
+    pub fn trimmed(&self) -> &[S] {
+        match self.coeffs.iter().rposition(|c| !c.is_zero()) {
+            Some(degree) => &self.coeffs[..=degree],
+            None => &[],
+        }
+    }
+
+    /// Returns the degree, ignoring vanishing leading coefficients. The zero polynomial has
+    /// degree 0.
+    pub fn degree(&self) -> usize {
+        self.trimmed().len().saturating_sub(1)
+    }
+}
+
+impl<S> PartialEq for Polynomial<S>
+where
+    S: Copy + Zero + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl<S> ops::Add for Polynomial<S>
+where
+    S: Copy + Zero,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (mut long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self.coeffs, rhs.coeffs)
+        } else {
+            (rhs.coeffs, self.coeffs)
+        };
+        for (c, d) in long.iter_mut().zip(short) {
+            *c = *c + d;
+        }
+        Polynomial { coeffs: long }
+    }
+}
+
+impl<S> ops::Neg for Polynomial<S>
+where
+    S: Copy + ops::Neg<Output = S>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<S> ops::Sub for Polynomial<S>
+where
+    S: Copy + Zero + ops::Neg<Output = S>,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<S> ops::Mul for Polynomial<S>
+where
+    S: Copy + Zero + ops::Mul<S, Output = S>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return Polynomial::default();
+        }
+        let mut coeffs = vec![S::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j] + *a * *b;
+            }
+        }
+        Polynomial { coeffs }
+    }
+}
+
+impl<S> ops::Mul<S> for Polynomial<S>
+where
+    S: Copy + ops::Mul<S, Output = S>,
+{
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(|c| c * rhs).collect(),
+        }
+    }
+}
+
+impl<S> ops::Div<S> for Polynomial<S>
+where
+    S: Copy + ops::Div<S, Output = S>,
+{
+    type Output = Self;
+    fn div(self, rhs: S) -> Self {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(|c| c / rhs).collect(),
+        }
+    }
+}
+
+impl<S> ops::Div for Polynomial<S>
+where
+    S: Float,
+{
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<S> ops::Rem for Polynomial<S>
+where
+    S: Float,
+{
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<S> Polynomial<S>
+where
+    S: Float,
+{
+    /// Evaluates the polynomial using Horner’s method.
+    pub fn eval(&self, x: S) -> S {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(S::zero(), |acc, c| acc * x + *c)
+    }
+
+    /// Returns the derivative.
+    pub fn derivative(&self) -> Self {
+        let mut n = S::zero();
+        let coeffs = self
+            .coeffs
+            .iter()
+            .skip(1)
+            .map(|c| {
+                n = n + S::one();
+                *c * n
+            })
+            .collect();
+        Polynomial { coeffs }
+    }
+
+    /// Returns the antiderivative that vanishes at 0.
+    pub fn antiderivative(&self) -> Self {
+        let mut n = S::zero();
+        let mut coeffs = vec![S::zero()];
+        coeffs.extend(self.coeffs.iter().map(|c| {
+            n = n + S::one();
+            *c / n
+        }));
+        Polynomial { coeffs }
+    }
+
+    /// Returns the composition p(q(x)).
+    pub fn compose(&self, q: &Self) -> Self {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Polynomial::default(), |acc, c| {
+                acc * q.clone() + Polynomial::from(vec![*c])
+            })
+    }
+
+    /// Divides by another polynomial, returning the quotient and the remainder.
+    ///
+    /// # Panics
+    /// - if the divisor is the zero polynomial
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor = divisor.trimmed();
+        let leading = *divisor.last().expect("division by the zero polynomial");
+        let mut remainder = self.trimmed().to_vec();
+        if remainder.len() < divisor.len() {
+            return (Polynomial::default(), Polynomial { coeffs: remainder });
+        }
+
+        let mut quotient = vec![S::zero(); remainder.len() - divisor.len() + 1];
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor.len() - 1] / leading;
+            quotient[i] = factor;
+            for (j, d) in divisor.iter().enumerate() {
+                remainder[i + j] = remainder[i + j] - factor * *d;
+            }
+        }
+        remainder.truncate(divisor.len() - 1);
+        (
+            Polynomial { coeffs: quotient },
+            Polynomial { coeffs: remainder },
+        )
+    }
+
+    /// Finds the real roots in [min, max]; see [`real_roots_in`].
+    pub fn real_roots_in(&self, min: S, max: S) -> Vec<S> {
+        real_roots_in(&self.coeffs, min, max)
+    }
+
+    /// Finds the real roots in [min, max] with their multiplicities; see
+    /// [`real_roots_with_multiplicity`].
+    pub fn real_roots_with_multiplicity(&self, min: S, max: S) -> Vec<Root<S>> {
+        real_roots_with_multiplicity(&self.coeffs, min, max)
+    }
+}
+
+#[test]
+fn test_polynomial() {
+    use super::Poly3x2d;
+    use cgmath::assert_abs_diff_eq;
+
+    let p = Polynomial::from(vec![1., -3., 0., 2.]);
+    let q = Polynomial::from(vec![-2., 1.]);
+
+    assert_eq!(p.degree(), 3);
+    assert_eq!(Polynomial::from(vec![1., 2., 0., 0.]).degree(), 1);
+    assert_eq!(Polynomial::<f64>::default().degree(), 0);
+    assert_eq!(
+        Polynomial::from(vec![1., 2., 0.]),
+        Polynomial::from(vec![1., 2.])
+    );
+
+    for &x in [-1.5, 0., 0.3, 2.].iter() {
+        let (px, qx) = (p.eval(x), q.eval(x));
+        assert_abs_diff_eq!(px, 1. - 3. * x + 2. * x * x * x);
+        assert_abs_diff_eq!((p.clone() + q.clone()).eval(x), px + qx, epsilon = 1e-12);
+        assert_abs_diff_eq!((p.clone() - q.clone()).eval(x), px - qx, epsilon = 1e-12);
+        assert_abs_diff_eq!((p.clone() * q.clone()).eval(x), px * qx, epsilon = 1e-12);
+        assert_abs_diff_eq!((p.clone() * 3.).eval(x), px * 3., epsilon = 1e-12);
+        assert_abs_diff_eq!((p.clone() / 4.).eval(x), px / 4., epsilon = 1e-12);
+        assert_abs_diff_eq!(p.compose(&q).eval(x), p.eval(qx), epsilon = 1e-12);
+        assert_abs_diff_eq!(p.derivative().eval(x), -3. + 6. * x * x, epsilon = 1e-12);
+    }
+    assert_eq!(p.antiderivative().derivative(), p);
+    assert_abs_diff_eq!(p.antiderivative().eval(0.), 0.);
+
+    // p = (x - 2)(2x² + 4x + 5) + 11
+    let (quotient, remainder) = p.div_rem(&q);
+    assert_eq!(quotient, Polynomial::from(vec![5., 4., 2.]));
+    assert_eq!(remainder, Polynomial::from(vec![11.]));
+    assert_eq!(p.clone() / q.clone(), quotient);
+    assert_eq!(p.clone() % q.clone(), remainder);
+    assert_eq!(quotient * q.clone() + remainder, p);
+    let (quotient, remainder) = q.div_rem(&p);
+    assert_eq!(quotient, Polynomial::default());
+    assert_eq!(remainder, q);
+
+    let cubic = Poly3 {
+        k: -6.,
+        x: 11.,
+        xx: -6.,
+        xxx: 1.,
+    };
+    let roots = Polynomial::from(cubic).real_roots_in(0., 10.);
+    assert_eq!(roots.len(), 3);
+    assert_abs_diff_eq!(roots[2], 3., epsilon = 1e-10);
+
+    let f = Poly3x2d {
+        k: 1.,
+        x: 2.,
+        y: -1.,
+        xy: 0.5,
+        xx: 0.,
+        yy: 1.,
+        xxy: 0.,
+        xyy: 0.,
+        xxx: 1.,
+        yyy: 0.,
+    };
+    let (x, y) = (Polynomial::from(cubic), Polynomial::from(vec![0., 1.]));
+    let subst = Polynomial::from(f.subst(&x.coeffs, &y.coeffs));
+    for &t in [-1., 0.5, 2.].iter() {
+        assert_abs_diff_eq!(subst.eval(t), f.eval(x.eval(t), y.eval(t)), epsilon = 1e-9);
+    }
+}